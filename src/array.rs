@@ -1,42 +1,136 @@
-use arraylib::{Array, ArrayExt};
-use crate::{Nil, Cons};
+use crate::Nil;
 
-pub trait IntoArray<T> {
-    type Repr: Array;
+/// Conversion of a hlist into an array.
+///
+/// This is implemented for hlists (of up to 12 elements) those elements all implement
+/// `Into<U>`. The length of the array is the length of the list.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, IntoArray};
+///
+/// let array: [i64; 3] = hlist![1i64, 2i32, 3u8].into_array();
+/// assert_eq!(array, [1, 2, 3]);
+///
+/// let array: [String; 2] = hlist!["hi", 'x'].into_array();
+/// assert_eq!(array, ["hi", "x"]);
+///
+/// let empty: [u8; 0] = hlist![].into_array();
+/// assert_eq!(empty, []);
+/// ```
+pub trait IntoArray<U> {
+    /// `[U; Self::LEN]`
+    type Array;
 
-    fn into_array(self) -> Self::Repr;
+    fn into_array(self) -> Self::Array;
 }
 
-impl<T> IntoArray<T> for Nil {
-    type Repr = [T; 0];
+/// Conversion of an array into a homogeneous hlist.
+///
+/// This is implemented for arrays of up to 12 elements. Lists of 2 or more elements also
+/// implement `From<[T; N]>` (a list of one element can't since it implements `From<T>`).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, FromArray, HList, IntoArray};
+///
+/// let list = <HList![i32, i32, i32]>::from_array([1, 2, 3]);
+/// assert_eq!(list, hlist![1, 2, 3]);
+///
+/// let list: HList![char, char] = ['a', 'b'].into();
+/// assert_eq!(list, hlist!['a', 'b']);
+///
+/// // round-trip
+/// let array: [char; 2] = list.into_array();
+/// assert_eq!(array, ['a', 'b']);
+/// ```
+pub trait FromArray<A> {
+    fn from_array(array: A) -> Self;
+}
+
+impl<U> IntoArray<U> for Nil {
+    type Array = [U; 0];
 
-    fn into_array(self) -> Self::Repr {
+    fn into_array(self) -> Self::Array {
         []
     }
 }
 
-impl<H, T, U> IntoArray<U> for Cons<H, T>
-where
-    H: Into<U>,
-    T: IntoArray<U>,
-    [U; <T as IntoArray<U>>::Repr::SIZE + 1]: Array,
-{
-    type Repr = [U; 1 + <T as IntoArray<U>>::Repr::SIZE];
-
-    fn into_array(self) -> Self::Repr {
-        [self.0].concat_arr(self.1.into_array())
+impl<T> FromArray<[T; 0]> for Nil {
+    fn from_array(_: [T; 0]) -> Self {
+        Nil
     }
 }
 
+/// `replace!(A, T)` -> `T`, used to repeat `T` for every type variable
+macro_rules! replace {
+    ($_ty:ident, $sub:tt) => {
+        $sub
+    };
+}
+
+// Lengths are written as literals (instead of e.g. `0 + 1 + 1`) because with
+// `generic_const_exprs` (enabled by the `nightly` feature) the latter cause cycle errors.
+macro_rules! impl_array {
+    ($len:literal: $( $types:ident ),*) => {
+        impl<U, $( $types, )*> IntoArray<U> for crate::HList![$( $types ),*]
+        where
+            $( $types: Into<U>, )*
+        {
+            type Array = [U; $len];
+
+            fn into_array(self) -> Self::Array {
+                #[allow(non_snake_case)]
+                let crate::hpat![$( $types ),*] = self;
+                [$( $types.into() ),*]
+            }
+        }
+
+        impl<T> FromArray<[T; $len]> for crate::HList![$( replace!($types, T) ),*] {
+            fn from_array(array: [T; $len]) -> Self {
+                #[allow(non_snake_case)]
+                let [$( $types ),*] = array;
+                crate::hlist![$( $types ),*]
+            }
+        }
+
+        impl_from_array!($len: $( $types ),*);
+    };
+}
+
+macro_rules! impl_from_array {
+    // `From<[T; 1]>` would conflict with `From<H> for Cons<H, Nil>`
+    (1: $a:ident) => {};
+    ($len:literal: $( $types:ident ),*) => {
+        impl<T> From<[T; $len]> for crate::HList![$( replace!($types, T) ),*] {
+            fn from(array: [T; $len]) -> Self {
+                Self::from_array(array)
+            }
+        }
+    };
+}
+
+impl_array!(1: A);
+impl_array!(2: A, B);
+impl_array!(3: A, B, C);
+impl_array!(4: A, B, C, D);
+impl_array!(5: A, B, C, D, E);
+impl_array!(6: A, B, C, D, E, F);
+impl_array!(7: A, B, C, D, E, F, G);
+impl_array!(8: A, B, C, D, E, F, G, H);
+impl_array!(9: A, B, C, D, E, F, G, H, I);
+impl_array!(10: A, B, C, D, E, F, G, H, I, J);
+impl_array!(11: A, B, C, D, E, F, G, H, I, J, K);
+impl_array!(12: A, B, C, D, E, F, G, H, I, J, K, L);
+
 #[test]
 fn into_array() {
-    assert_eq!(
-        hlist![1, 2, 3].into_array(),
-        [1, 2, 3]
-    );
+    use crate::{hlist, Cons};
 
-    assert_eq!(
-        Nil.into_array(),
-        []
-    );
+    let array: [i32; 3] = hlist![1, 2, 3].into_array();
+    assert_eq!(array, [1, 2, 3]);
+    assert_eq!(IntoArray::<i32>::into_array(Nil), []);
+    assert_eq!(Cons::<_, Nil>::from_array([1]), hlist![1]);
 }
@@ -0,0 +1,263 @@
+use crate::{
+    succnum::{Succ, Zero},
+    Cons, Nil,
+};
+
+/// Position-indexed access to hlist elements.
+///
+/// Unlike [`Get`](crate::Get), which searches elements by type, this trait accesses elements by
+/// their position, so it works with lists that contain the same type multiple times. The index is
+/// a type-level number from the [`succnum`](crate::succnum) module.
+///
+/// Since the index can't be inferred, it's usually passed via the `at`, `at_mut` and `into_at`
+/// inherent methods of [`Cons`].
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{
+///     hlist,
+///     succnum::{U0, U1},
+/// };
+///
+/// let mut list = hlist![17, 42, 'x'];
+///
+/// assert_eq!(list.at::<U0>(), &17);
+/// assert_eq!(list.at::<U1>(), &42);
+///
+/// *list.at_mut::<U1>() = 0;
+/// assert_eq!(list, hlist![17, 0, 'x']);
+///
+/// let c: char = list.into_at::<minihlist::succnum::U2>();
+/// assert_eq!(c, 'x');
+/// ```
+///
+/// It's a compile error to index out of bounds:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, succnum::U2};
+///
+/// let list = hlist![17, 42];
+/// let _ = list.at::<U2>();
+/// ```
+pub trait At<Idx> {
+    /// Type of the element at `Idx`
+    type Output;
+
+    /// Borrows the element at `Idx`.
+    fn at(&self) -> &Self::Output;
+
+    /// Mutably borrows the element at `Idx`.
+    fn at_mut(&mut self) -> &mut Self::Output;
+
+    /// Consumes the list, returning the element at `Idx`.
+    fn into_at(self) -> Self::Output;
+}
+
+impl<H, T> At<Zero> for Cons<H, T> {
+    type Output = H;
+
+    fn at(&self) -> &Self::Output {
+        &self.0
+    }
+
+    fn at_mut(&mut self) -> &mut Self::Output {
+        &mut self.0
+    }
+
+    fn into_at(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<H, T, Idx> At<Succ<Idx>> for Cons<H, T>
+where
+    T: At<Idx>,
+{
+    type Output = T::Output;
+
+    fn at(&self) -> &Self::Output {
+        self.1.at()
+    }
+
+    fn at_mut(&mut self) -> &mut Self::Output {
+        self.1.at_mut()
+    }
+
+    fn into_at(self) -> Self::Output {
+        self.1.into_at()
+    }
+}
+
+/// Position-indexed remove from hlist.
+///
+/// This trait is very similar to [`Remove`](crate::Remove) but, just like [`At`], it uses
+/// position instead of type to find the element.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, succnum::U1};
+///
+/// let (x, rest) = hlist![17, 42, 'x'].remove_at::<U1>();
+/// assert_eq!(x, 42);
+/// assert_eq!(rest, hlist![17, 'x']);
+/// ```
+pub trait RemoveAt<Idx>: At<Idx> {
+    /// The list without the removed element
+    type Rest;
+
+    /// Removes the element at `Idx`, returning tuple of the element and the rest of the list.
+    fn remove_at(self) -> (Self::Output, Self::Rest);
+}
+
+impl<H, T> RemoveAt<Zero> for Cons<H, T> {
+    type Rest = T;
+
+    fn remove_at(self) -> (Self::Output, Self::Rest) {
+        let Cons(head, tail) = self;
+        (head, tail)
+    }
+}
+
+impl<H, T, Idx> RemoveAt<Succ<Idx>> for Cons<H, T>
+where
+    T: RemoveAt<Idx>,
+{
+    type Rest = Cons<H, T::Rest>;
+
+    fn remove_at(self) -> (Self::Output, Self::Rest) {
+        let Cons(head, tail) = self;
+        let (ret, tail_rest) = tail.remove_at();
+        (ret, Cons(head, tail_rest))
+    }
+}
+
+/// Position-indexed insert into hlist.
+///
+/// This is the inverse of [`RemoveAt`]: it inserts `V` so that it ends up at position `Idx`.
+/// `Idx` can be anything from `U0` (insert at the front) to the length of the list (insert at the
+/// back).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{
+///     hlist,
+///     succnum::{U0, U1, U3},
+/// };
+///
+/// let list = hlist![17, 'x'];
+///
+/// assert_eq!(list.insert_at::<U1, _>("hi"), hlist![17, "hi", 'x']);
+/// assert_eq!(list.insert_at::<U0, _>("hi"), hlist!["hi", 17, 'x']);
+///
+/// let list = hlist![].insert_at::<U0, _>(1).insert_at::<U1, _>(2).insert_at::<U0, _>(0);
+/// assert_eq!(list.insert_at::<U3, _>(3), hlist![0, 1, 2, 3]);
+/// ```
+///
+/// It's a compile error to insert out of bounds:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, succnum::U3};
+///
+/// let _ = hlist![17, 42].insert_at::<U3, _>('x');
+/// ```
+pub trait InsertAt<Idx, V> {
+    /// The list with the inserted element
+    type Output;
+
+    /// Inserts `val` at `Idx`.
+    fn insert_at(self, val: V) -> Self::Output;
+}
+
+impl<V> InsertAt<Zero, V> for Nil {
+    type Output = Cons<V, Nil>;
+
+    fn insert_at(self, val: V) -> Self::Output {
+        Cons(val, self)
+    }
+}
+
+impl<H, T, V> InsertAt<Zero, V> for Cons<H, T> {
+    type Output = Cons<V, Self>;
+
+    fn insert_at(self, val: V) -> Self::Output {
+        Cons(val, self)
+    }
+}
+
+impl<H, T, Idx, V> InsertAt<Succ<Idx>, V> for Cons<H, T>
+where
+    T: InsertAt<Idx, V>,
+{
+    type Output = Cons<H, T::Output>;
+
+    fn insert_at(self, val: V) -> Self::Output {
+        let Cons(head, tail) = self;
+        Cons(head, tail.insert_at(val))
+    }
+}
+
+impl<H, T> Cons<H, T> {
+    /// Borrows the element at `Idx`. See [`At`] for more.
+    pub fn at<Idx>(&self) -> &<Self as At<Idx>>::Output
+    where
+        Self: At<Idx>,
+    {
+        At::at(self)
+    }
+
+    /// Mutably borrows the element at `Idx`. See [`At`] for more.
+    pub fn at_mut<Idx>(&mut self) -> &mut <Self as At<Idx>>::Output
+    where
+        Self: At<Idx>,
+    {
+        At::at_mut(self)
+    }
+
+    /// Consumes the list, returning the element at `Idx`. See [`At`] for more.
+    pub fn into_at<Idx>(self) -> <Self as At<Idx>>::Output
+    where
+        Self: At<Idx>,
+    {
+        At::into_at(self)
+    }
+
+    /// Removes the element at `Idx`. See [`RemoveAt`] for more.
+    pub fn remove_at<Idx>(self) -> (<Self as At<Idx>>::Output, <Self as RemoveAt<Idx>>::Rest)
+    where
+        Self: RemoveAt<Idx>,
+    {
+        RemoveAt::remove_at(self)
+    }
+
+    /// Inserts `val` at `Idx`. See [`InsertAt`] for more.
+    pub fn insert_at<Idx, V>(self, val: V) -> <Self as InsertAt<Idx, V>>::Output
+    where
+        Self: InsertAt<Idx, V>,
+    {
+        InsertAt::insert_at(self, val)
+    }
+}
+
+impl Nil {
+    /// Inserts `val` at `Idx`. See [`InsertAt`] for more.
+    pub fn insert_at<Idx, V>(self, val: V) -> <Self as InsertAt<Idx, V>>::Output
+    where
+        Self: InsertAt<Idx, V>,
+    {
+        InsertAt::insert_at(self, val)
+    }
+}
+
+#[test]
+fn same_types() {
+    use crate::{hlist, succnum::*};
+
+    let mut list = hlist![1, 2, 3];
+    *list.at_mut::<U2>() += 10;
+
+    assert_eq!(list.at::<U0>(), &1);
+    assert_eq!(list.remove_at::<U1>(), (2, hlist![1, 13]));
+}
@@ -0,0 +1,53 @@
+use crate::{Cons, Extend, Nil};
+
+/// Concatenate a hlist of hlists.
+///
+/// This joins the inner lists one level deep (via [`Extend`]), e.g.
+/// `HList![HList![A, B], HList![], HList![C]]` becomes `HList![A, B, C]`. All elements must be
+/// hlists. See `Flatten` (requires `nightly` feature) for a version that flattens lists of any
+/// depth and allows non-list elements.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Concat};
+///
+/// let list = hlist![hlist![1, 'x'], hlist![], hlist!["hi", hlist![2]]];
+/// assert_eq!(list.concat(), hlist![1, 'x', "hi", hlist![2]]);
+/// ```
+///
+/// It's a compile error to concat a list with non-list elements:
+///
+/// ```compile_fail,E0599
+/// use minihlist::{hlist, Concat};
+///
+/// let _ = hlist![hlist![1], 2].concat();
+/// ```
+pub trait Concat {
+    type Output;
+
+    fn concat(self) -> Self::Output;
+}
+
+impl<H, T> Concat for Cons<H, T>
+where
+    T: Concat,
+    H: Extend<T::Output>,
+{
+    type Output = H::Output;
+
+    #[inline]
+    fn concat(self) -> Self::Output {
+        let Cons(head, tail) = self;
+        head.extend(tail.concat())
+    }
+}
+
+impl Concat for Nil {
+    type Output = Nil;
+
+    #[inline]
+    fn concat(self) -> Self::Output {
+        Nil
+    }
+}
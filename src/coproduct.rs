@@ -0,0 +1,363 @@
+use crate::{
+    succnum::{Succ, Zero},
+    Cons, Func, Nil, Poly,
+};
+
+/// A heterogeneous sum type, the dual of [`Cons`].
+///
+/// While `HList![A, B, C]` holds *all* of `A`, `B` and `C`, `Coprod![A, B, C]` holds *one of*
+/// them. `Inl(h)` means that the value is of the first type, `Inr(t)` means that it's one of the
+/// types of the rest of the coproduct. [`CNil`] is the empty coproduct that can't be
+/// constructed.
+///
+/// Values are usually created by [`Inject::inject`] and deconstructed by [`Uninject`] or
+/// [`CoproductFold`].
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{Coprod, Coproduct, Inject, Uninject};
+///
+/// type IntOrStr = Coprod![i32, &'static str];
+///
+/// let i = IntOrStr::inject(17);
+/// let s = IntOrStr::inject("hi");
+///
+/// assert_eq!(i, Coproduct::Inl(17));
+/// assert_eq!(s, Coproduct::Inr(Coproduct::Inl("hi")));
+///
+/// assert_eq!(i.get(), Some(&17));
+/// assert_eq!(s.get::<i32, _>(), None);
+/// assert_eq!(s.take(), Some("hi"));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Coproduct<H, T> {
+    /// The value is of type `H`
+    Inl(H),
+    /// The value is of one of the types of `T`
+    Inr(T),
+}
+
+impl<H, T> Coproduct<H, T> {
+    /// Creates a coproduct from a value of one of its types. See [`Inject`] for more.
+    pub fn inject<X, Idx>(val: X) -> Self
+    where
+        Self: Inject<X, Idx>,
+    {
+        Inject::inject(val)
+    }
+
+    /// Borrows the value if it is of type `X`. See [`Uninject`] for more.
+    pub fn get<X, Idx>(&self) -> Option<&X>
+    where
+        Self: Uninject<X, Idx>,
+    {
+        Uninject::get(self)
+    }
+
+    /// Mutably borrows the value if it is of type `X`. See [`Uninject`] for more.
+    pub fn get_mut<X, Idx>(&mut self) -> Option<&mut X>
+    where
+        Self: Uninject<X, Idx>,
+    {
+        Uninject::get_mut(self)
+    }
+
+    /// Returns the value if it is of type `X`. See [`Uninject`] for more.
+    pub fn take<X, Idx>(self) -> Option<X>
+    where
+        Self: Uninject<X, Idx>,
+    {
+        Uninject::take(self)
+    }
+
+    /// Returns the value if it is of type `X` or the coproduct of the rest of the types
+    /// otherwise. See [`Uninject`] for more.
+    pub fn uninject<X, Idx>(self) -> Result<X, <Self as Uninject<X, Idx>>::Rest>
+    where
+        Self: Uninject<X, Idx>,
+    {
+        Uninject::uninject(self)
+    }
+
+    /// Folds the coproduct into a single value. See [`CoproductFold`] for more.
+    pub fn fold<F, R>(self, f: F) -> R
+    where
+        Self: CoproductFold<F, R>,
+    {
+        CoproductFold::fold(self, f)
+    }
+}
+
+/// The empty coproduct.
+///
+/// It has no values, so `Coprod![A, B]` (which is `Coproduct<A, Coproduct<B, CNil>>`) can only be
+/// constructed as `Inl(a)` or `Inr(Inl(b))`.
+///
+/// See [`Coproduct`] for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum CNil {}
+
+/// Type-directed construction of a coproduct.
+///
+/// This trait allows to create a coproduct from a value of one of its types. Just like with
+/// [`Get`](crate::Get), the type must be unique in the coproduct.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{Coprod, Coproduct, Inject};
+///
+/// let c: Coprod![i32, bool, char] = Coproduct::inject(true);
+/// assert_eq!(c, Coproduct::Inr(Coproduct::Inl(true)));
+/// ```
+///
+/// It's a compile error to inject a value of a type that is not in the coproduct:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{Coprod, Coproduct, Inject};
+///
+/// let c: Coprod![i32, bool] = Coproduct::inject('x');
+/// ```
+pub trait Inject<T, Idx> {
+    fn inject(val: T) -> Self;
+}
+
+impl<H, T> Inject<H, Zero> for Coproduct<H, T> {
+    fn inject(val: H) -> Self {
+        Coproduct::Inl(val)
+    }
+}
+
+impl<H, T, X, Idx> Inject<X, Succ<Idx>> for Coproduct<H, T>
+where
+    T: Inject<X, Idx>,
+{
+    fn inject(val: X) -> Self {
+        Coproduct::Inr(T::inject(val))
+    }
+}
+
+/// Type-directed deconstruction of a coproduct.
+///
+/// This trait allows to check if a coproduct holds a value of a particular type and extract it.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{Coprod, Coproduct, Inject, Uninject};
+///
+/// type C = Coprod![i32, bool, char];
+///
+/// let c = C::inject(true);
+///
+/// assert_eq!(c.get::<bool, _>(), Some(&true));
+/// assert_eq!(c.get::<i32, _>(), None);
+///
+/// // on failure `uninject` returns a coproduct of the rest of the types
+/// let rest: Result<i32, Coprod![bool, char]> = c.uninject();
+/// assert_eq!(rest, Err(Coproduct::inject(true)));
+///
+/// let b: Option<bool> = c.take();
+/// assert_eq!(b, Some(true));
+/// ```
+pub trait Uninject<T, Idx>: Inject<T, Idx> {
+    /// Coproduct of the rest of the types
+    type Rest;
+
+    /// Borrows the value if it is of type `T`.
+    fn get(&self) -> Option<&T>;
+
+    /// Mutably borrows the value if it is of type `T`.
+    fn get_mut(&mut self) -> Option<&mut T>;
+
+    /// Returns the value if it is of type `T` or the coproduct of the rest of the types otherwise.
+    fn uninject(self) -> Result<T, Self::Rest>;
+
+    /// Returns the value if it is of type `T`.
+    #[inline]
+    fn take(self) -> Option<T>
+    where
+        Self: Sized,
+    {
+        self.uninject().ok()
+    }
+}
+
+impl<H, T> Uninject<H, Zero> for Coproduct<H, T> {
+    type Rest = T;
+
+    fn get(&self) -> Option<&H> {
+        match self {
+            Coproduct::Inl(head) => Some(head),
+            Coproduct::Inr(_) => None,
+        }
+    }
+
+    fn get_mut(&mut self) -> Option<&mut H> {
+        match self {
+            Coproduct::Inl(head) => Some(head),
+            Coproduct::Inr(_) => None,
+        }
+    }
+
+    fn uninject(self) -> Result<H, Self::Rest> {
+        match self {
+            Coproduct::Inl(head) => Ok(head),
+            Coproduct::Inr(tail) => Err(tail),
+        }
+    }
+}
+
+impl<H, T, X, Idx> Uninject<X, Succ<Idx>> for Coproduct<H, T>
+where
+    T: Uninject<X, Idx>,
+{
+    type Rest = Coproduct<H, T::Rest>;
+
+    fn get(&self) -> Option<&X> {
+        match self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(tail) => tail.get(),
+        }
+    }
+
+    fn get_mut(&mut self) -> Option<&mut X> {
+        match self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(tail) => tail.get_mut(),
+        }
+    }
+
+    fn uninject(self) -> Result<X, Self::Rest> {
+        match self {
+            Coproduct::Inl(head) => Err(Coproduct::Inl(head)),
+            Coproduct::Inr(tail) => tail.uninject().map_err(Coproduct::Inr),
+        }
+    }
+}
+
+/// Fold a coproduct into a single value.
+///
+/// This applies the function that corresponds to the type of the value held by the coproduct.
+///
+/// The function(s) type `F` can be:
+/// 1. A `HList` of `FnOnce` functions (one for each type)
+/// 2. A single `FnOnce` function (for homogenous coproducts)
+/// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is used for all the
+///    rest of the types
+/// 4. A polymorphic function ([`Poly`]) that can be applied to all types
+///
+/// All functions must return the same type `R`.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Coprod, Coproduct, CoproductFold, Inject};
+///
+/// type C = Coprod![i32, bool, &'static str];
+///
+/// let f = || hlist![|i: i32| i.to_string(), |b: bool| b.to_string(), |s: &str| s.to_owned()];
+///
+/// assert_eq!(C::inject(1).fold(f()), "1");
+/// assert_eq!(C::inject(false).fold(f()), "false");
+/// assert_eq!(C::inject("hi").fold(f()), "hi");
+/// ```
+///
+/// Using a single function for the homogenous tail:
+///
+/// ```
+/// use minihlist::{hlist, Coprod, Coproduct, CoproductFold, Inject};
+///
+/// let c: Coprod![&str, u8, u8] = Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(3)));
+/// let len = c.fold(hlist![|s: &str| s.len(), |i: u8| usize::from(i)]);
+/// assert_eq!(len, 3);
+/// ```
+///
+/// Using [`Poly`]:
+///
+/// ```
+/// use minihlist::{poly_fn, Coprod, Coproduct, CoproductFold, Inject};
+/// use std::fmt::Debug;
+///
+/// let c: Coprod![i32, char] = Coproduct::inject('x');
+/// let s = c.fold(poly_fn![[T: Debug] |x: T| -> String { format!("{:?}", x) }]);
+/// assert_eq!(s, "'x'");
+/// ```
+pub trait CoproductFold<F, R> {
+    fn fold(self, f: F) -> R;
+}
+
+// F{H,M,T} - function {head,middle,tail} parts
+// {H,T}    - {head,tail} of the coproduct being folded
+impl<FH, FM, FT, H, T, R> CoproductFold<Cons<FH, Cons<FM, FT>>, R> for Coproduct<H, T>
+where
+    FH: FnOnce(H) -> R,
+    T: CoproductFold<Cons<FM, FT>, R>,
+{
+    #[inline]
+    fn fold(self, Cons(fh, ft): Cons<FH, Cons<FM, FT>>) -> R {
+        match self {
+            Coproduct::Inl(head) => fh(head),
+            Coproduct::Inr(tail) => tail.fold(ft),
+        }
+    }
+}
+
+impl<F, H, R> CoproductFold<Cons<F, Nil>, R> for Coproduct<H, CNil>
+where
+    F: FnOnce(H) -> R,
+{
+    #[inline]
+    fn fold(self, Cons(f, _): Cons<F, Nil>) -> R {
+        match self {
+            Coproduct::Inl(head) => f(head),
+            Coproduct::Inr(never) => match never {},
+        }
+    }
+}
+
+impl<F, H, T, R> CoproductFold<F, R> for Coproduct<H, T>
+where
+    F: FnOnce(H) -> R,
+    T: CoproductFold<F, R>,
+{
+    #[inline]
+    fn fold(self, f: F) -> R {
+        match self {
+            Coproduct::Inl(head) => f(head),
+            Coproduct::Inr(tail) => tail.fold(f),
+        }
+    }
+}
+
+impl<F, H, M, T, R> CoproductFold<Cons<F, Nil>, R> for Coproduct<H, Coproduct<M, T>>
+where
+    Self: CoproductFold<F, R>,
+{
+    #[inline]
+    fn fold(self, Cons(f, _): Cons<F, Nil>) -> R {
+        self.fold(f)
+    }
+}
+
+impl<P, H, T, R> CoproductFold<Poly<P>, R> for Coproduct<H, T>
+where
+    P: Func<H, Output = R>,
+    T: CoproductFold<Poly<P>, R>,
+{
+    #[inline]
+    fn fold(self, Poly(mut p): Poly<P>) -> R {
+        match self {
+            Coproduct::Inl(head) => p.call(head),
+            Coproduct::Inr(tail) => tail.fold(Poly(p)),
+        }
+    }
+}
+
+impl<F, R> CoproductFold<F, R> for CNil {
+    #[inline]
+    fn fold(self, _: F) -> R {
+        match self {}
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{Cons, Nil};
+
+/// Type-level boolean, either [`True`] or [`False`].
+pub trait Bool: private::Sealed {
+    /// Value-level counterpart of the type
+    const VALUE: bool;
+}
+
+/// Type-level `true`, see [`Bool`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct True;
+
+/// Type-level `false`, see [`Bool`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct False;
+
+impl Bool for True {
+    const VALUE: bool = true;
+}
+
+impl Bool for False {
+    const VALUE: bool = false;
+}
+
+/// Type-level function from element types to [`Bool`]s, used by [`Filter`].
+///
+/// Predicates are usually zero-sized marker types that implement this trait for every element
+/// type they should be able to judge. Rust can't (without specialization) check if a type
+/// implements some trait, so types have to opt in explicitly, e.g. with a blanket impl for all
+/// types implementing your marker trait plus impls for other types.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{False, Predicate, True};
+///
+/// trait Component {}
+///
+/// struct Position(f32, f32);
+/// impl Component for Position {}
+///
+/// struct IsComponent;
+///
+/// impl<T: Component> Predicate<T> for IsComponent {
+///     type Output = True;
+/// }
+///
+/// impl Predicate<&str> for IsComponent {
+///     type Output = False;
+/// }
+/// ```
+pub trait Predicate<T> {
+    type Output: Bool;
+}
+
+/// Type-directed filter of a hlist.
+///
+/// This keeps elements for which the predicate `P` (see [`Predicate`]) returns [`True`] and drops
+/// the ones for which it returns [`False`]. [`partition`](Filter::partition) returns the dropped
+/// elements too.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, False, Predicate, True};
+///
+/// struct IsNumber;
+///
+/// impl Predicate<i32> for IsNumber {
+///     type Output = True;
+/// }
+///
+/// impl Predicate<f64> for IsNumber {
+///     type Output = True;
+/// }
+///
+/// impl Predicate<&str> for IsNumber {
+///     type Output = False;
+/// }
+///
+/// let list = hlist![1, "one", 2.5, "two"];
+///
+/// assert_eq!(list.filter::<IsNumber>(), hlist![1, 2.5]);
+///
+/// let (numbers, rest) = list.partition::<IsNumber>();
+/// assert_eq!(numbers, hlist![1, 2.5]);
+/// assert_eq!(rest, hlist!["one", "two"]);
+/// ```
+///
+/// It's a compile error to filter a list with an element the predicate doesn't know:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, Predicate, True};
+///
+/// struct IsNumber;
+///
+/// impl Predicate<i32> for IsNumber {
+///     type Output = True;
+/// }
+///
+/// let _ = hlist![1, 'x'].filter::<IsNumber>();
+/// ```
+pub trait Filter<P> {
+    /// Elements for which `P` returns [`True`]
+    type Output;
+
+    /// Elements for which `P` returns [`False`]
+    type Rest;
+
+    /// Returns elements for which `P` returns [`True`], dropping the rest.
+    #[inline]
+    fn filter(self) -> Self::Output
+    where
+        Self: Sized,
+    {
+        self.partition().0
+    }
+
+    /// Splits the list into elements for which `P` returns [`True`] and the rest.
+    fn partition(self) -> (Self::Output, Self::Rest);
+}
+
+impl<P> Filter<P> for Nil {
+    type Output = Nil;
+    type Rest = Nil;
+
+    #[inline]
+    fn partition(self) -> (Self::Output, Self::Rest) {
+        (Nil, Nil)
+    }
+}
+
+impl<P, H, T> Filter<P> for Cons<H, T>
+where
+    P: Predicate<H>,
+    T: Filter<P>,
+    P::Output: private::Select<H, T::Output, T::Rest>,
+{
+    type Output = <P::Output as private::Select<H, T::Output, T::Rest>>::Kept;
+    type Rest = <P::Output as private::Select<H, T::Output, T::Rest>>::Dropped;
+
+    #[inline]
+    fn partition(self) -> (Self::Output, Self::Rest) {
+        let Cons(head, tail) = self;
+        let (kept, dropped) = tail.partition();
+        <P::Output as private::Select<_, _, _>>::select(head, kept, dropped)
+    }
+}
+
+impl<H, T> Cons<H, T> {
+    /// Returns elements for which `P` returns [`True`]. See [`Filter`] for more.
+    pub fn filter<P>(self) -> <Self as Filter<P>>::Output
+    where
+        Self: Filter<P>,
+    {
+        Filter::filter(self)
+    }
+
+    /// Splits the list into elements for which `P` returns [`True`] and the rest. See [`Filter`]
+    /// for more.
+    pub fn partition<P>(self) -> (<Self as Filter<P>>::Output, <Self as Filter<P>>::Rest)
+    where
+        Self: Filter<P>,
+    {
+        Filter::partition(self)
+    }
+}
+
+impl Nil {
+    /// Returns elements for which `P` returns [`True`]. See [`Filter`] for more.
+    pub fn filter<P>(self) -> <Self as Filter<P>>::Output
+    where
+        Self: Filter<P>,
+    {
+        Filter::filter(self)
+    }
+
+    /// Splits the list into elements for which `P` returns [`True`] and the rest. See [`Filter`]
+    /// for more.
+    pub fn partition<P>(self) -> (<Self as Filter<P>>::Output, <Self as Filter<P>>::Rest)
+    where
+        Self: Filter<P>,
+    {
+        Filter::partition(self)
+    }
+}
+
+mod private {
+    use super::{False, True};
+    use crate::Cons;
+
+    pub trait Sealed {}
+
+    impl Sealed for True {}
+    impl Sealed for False {}
+
+    /// Trait implemented for [`True`] and [`False`], used to put an element into either kept or
+    /// dropped elements in [`Filter`](super::Filter).
+    pub trait Select<H, Kept, Dropped> {
+        type Kept;
+        type Dropped;
+
+        fn select(head: H, kept: Kept, dropped: Dropped) -> (Self::Kept, Self::Dropped);
+    }
+
+    impl<H, K, D> Select<H, K, D> for True {
+        type Kept = Cons<H, K>;
+        type Dropped = D;
+
+        #[inline]
+        fn select(head: H, kept: K, dropped: D) -> (Self::Kept, Self::Dropped) {
+            (Cons(head, kept), dropped)
+        }
+    }
+
+    impl<H, K, D> Select<H, K, D> for False {
+        type Kept = K;
+        type Dropped = Cons<H, D>;
+
+        #[inline]
+        fn select(head: H, kept: K, dropped: D) -> (Self::Kept, Self::Dropped) {
+            (kept, Cons(head, dropped))
+        }
+    }
+}
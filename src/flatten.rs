@@ -1,6 +1,29 @@
-use crate::{Cons, Extend, Nil};
+use crate::{Cons, Nil};
 
+/// Deeply flatten a hlist.
 ///
+/// Elements that are hlists are (recursively) flattened into the outer list, other elements are
+/// kept as is, e.g. `HList![A, HList![B, HList![C]], HList![]]` becomes `HList![A, B, C]`. See
+/// [`Concat`](crate::Concat) for a stable version that flattens only one level of lists of lists.
+///
+/// Note that types that merely *contain* a hlist (e.g. `struct Wrap(Nil)`) are not flattened.
+/// Also note that element types must be known when `flatten` is called (e.g. integer literals may
+/// need a suffix).
+///
+/// This requires the `nightly` feature, which enables the incomplete `specialization` and
+/// `generic_const_exprs` features for the whole crate. Hlists are told apart from other types by
+/// a specialized associated const which is then used in a type. `min_specialization` doesn't
+/// allow specializing associated consts, and specialized associated types (which wouldn't need
+/// `generic_const_exprs`) are not normalized during type checking.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Flatten};
+///
+/// let list = hlist![1u8, hlist![2i32, hlist!["3", hlist![]]], hlist![], 'x'];
+/// assert_eq!(list.flatten(), hlist![1, 2, "3", 'x']);
+/// ```
 #[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
 pub trait Flatten {
     type Output;
@@ -10,38 +33,18 @@ pub trait Flatten {
 
 impl<H, T> Flatten for Cons<H, T>
 where
-    private::Is<H>: private::NotHList,
     T: Flatten,
+    private::Is<{ <H as private::IsHList>::VALUE }>: private::Step<H, T::Output>,
 {
-    type Output = Cons<H, T::Output>;
+    type Output =
+        <private::Is<{ <H as private::IsHList>::VALUE }> as private::Step<H, T::Output>>::Output;
 
     fn flatten(self) -> Self::Output {
         let Cons(head, tail) = self;
-        Cons(head, tail.flatten())
-    }
-}
-
-impl<T> Flatten for Cons<Nil, T>
-where
-    T: Flatten,
-{
-    type Output = T::Output;
-
-    fn flatten(self) -> Self::Output {
-        self.1.flatten()
-    }
-}
-
-impl<HH, HT, T> Flatten for Cons<Cons<HH, HT>, T>
-where
-    Cons<HH, HT>: Extend<T::Output>,
-    T: Flatten,
-{
-    type Output = <Cons<HH, HT> as Extend<T::Output>>::Output;
-
-    fn flatten(self) -> Self::Output {
-        let Cons(head, tail) = self;
-        head.extend(tail.flatten())
+        <private::Is<{ <H as private::IsHList>::VALUE }> as private::Step<_, _>>::step(
+            head,
+            tail.flatten(),
+        )
     }
 }
 
@@ -54,24 +57,63 @@ impl Flatten for Nil {
 }
 
 mod private {
-    use crate::{Cons, Nil};
+    use super::Flatten;
+    use crate::{Cons, Extend, Nil};
 
-    /// Trait implemented for all types except `Is<`[`Cons`]`>` and `Is<`[`Nil`]`>`.
+    /// Trait implemented for all types, `VALUE` is `true` only for [`Cons`] and [`Nil`].
     /// It is needed to implement traits differently for `HList`s and for other types.
     /// (e.g.: [`Flatten`] is implemented using this trait)
     ///
-    /// [`Cons`]: crate::Cons
-    /// [`Nil`]: crate::Nil
-    pub auto trait NotHList {}
-    impl !NotHList for Is<Nil> {}
-    impl<H, T> !NotHList for Is<Cons<H, T>> {}
-
-    /// `Is` is being used to prevent false negative errors with types those include `Nil` or
-    /// `Cons<_, _>` (e.g.: `struct Test(Nil)`). See [#3] & test `hlist_with_hlist_inside_struct` down
-    /// below for more.
+    /// Unlike an auto trait, this doesn't look into fields, so types those include `Nil` or
+    /// `Cons<_, _>` (e.g.: `struct Test(Nil)`) are not hlists. See [#3] & test
+    /// `hlist_with_hlist_inside_struct` down below for more.
     ///
     /// [#3]: https://github.com/WaffleLapkin/minihlist/issues/3
-    pub struct Is<T>(T);
+    pub trait IsHList {
+        const VALUE: bool;
+    }
+
+    impl<T> IsHList for T {
+        default const VALUE: bool = false;
+    }
+
+    impl IsHList for Nil {
+        const VALUE: bool = true;
+    }
+
+    impl<H, T> IsHList for Cons<H, T> {
+        const VALUE: bool = true;
+    }
+
+    /// Type-level version of [`IsHList::VALUE`].
+    pub struct Is<const HLIST: bool>;
+
+    /// Prepends (flattened if it's a hlist) head to the flattened tail.
+    pub trait Step<H, T> {
+        type Output;
+
+        fn step(head: H, tail: T) -> Self::Output;
+    }
+
+    impl<H, T> Step<H, T> for Is<false> {
+        type Output = Cons<H, T>;
+
+        fn step(head: H, tail: T) -> Self::Output {
+            Cons(head, tail)
+        }
+    }
+
+    impl<H, T> Step<H, T> for Is<true>
+    where
+        H: Flatten,
+        H::Output: Extend<T>,
+    {
+        type Output = <H::Output as Extend<T>>::Output;
+
+        fn step(head: H, tail: T) -> Self::Output {
+            head.flatten().extend(tail)
+        }
+    }
 }
 
 #[test]
@@ -0,0 +1,153 @@
+use crate::{Cons, Nil};
+use core::fmt::{self, Debug, Display};
+
+/// Formats the list as `hlist![a, b, c]` (respecting `{:#?}`).
+///
+/// This is only implemented for proper lists (ones that end with [`Nil`]).
+///
+/// ```
+/// use minihlist::hlist;
+///
+/// let list = hlist![1, "x", hlist!['c']];
+///
+/// assert_eq!(format!("{:?}", list), r#"hlist![1, "x", hlist!['c']]"#);
+/// assert_eq!(
+///     format!("{:#?}", list),
+///     r#"hlist![
+///     1,
+///     "x",
+///     hlist![
+///         'c',
+///     ],
+/// ]"#,
+/// );
+/// ```
+impl<H, T> Debug for Cons<H, T>
+where
+    H: Debug,
+    T: private::DebugElements,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("hlist!")?;
+        let mut list = f.debug_list();
+        private::DebugElements::entries(self, &mut list);
+        list.finish()
+    }
+}
+
+/// Formats the list as `hlist![]`.
+///
+/// ```
+/// use minihlist::hlist;
+///
+/// assert_eq!(format!("{:?}", hlist![]), "hlist![]");
+/// ```
+impl Debug for Nil {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("hlist![]")
+    }
+}
+
+/// Helper struct for displaying hlists with all elements implementing [`Display`].
+///
+/// This struct is created by the [`HList::display_with`](crate::HList::display_with) method.
+pub struct DisplayWith<'a, L, S> {
+    list: &'a L,
+    sep: S,
+}
+
+impl<'a, L, S> DisplayWith<'a, L, S> {
+    pub(crate) fn new(list: &'a L, sep: S) -> Self {
+        Self { list, sep }
+    }
+}
+
+impl<L, S> Display for DisplayWith<'_, L, S>
+where
+    L: private::DisplayElements,
+    S: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.list.fmt_elements(f, &self.sep, true)
+    }
+}
+
+impl<L, S> Debug for DisplayWith<'_, L, S>
+where
+    L: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayWith")
+            .field("list", &self.list)
+            .field("sep", &self.sep)
+            .finish()
+    }
+}
+
+mod private {
+    use crate::{Cons, Nil};
+    use core::fmt::{self, Debug, Display};
+
+    /// Trait implemented for hlists those elements implement [`Debug`], used to implement
+    /// [`Debug`] for [`Cons`].
+    pub trait DebugElements {
+        fn entries(&self, list: &mut fmt::DebugList<'_, '_>);
+    }
+
+    impl<H, T> DebugElements for Cons<H, T>
+    where
+        H: Debug,
+        T: DebugElements,
+    {
+        fn entries(&self, list: &mut fmt::DebugList<'_, '_>) {
+            list.entry(&self.0);
+            self.1.entries(list);
+        }
+    }
+
+    impl DebugElements for Nil {
+        fn entries(&self, _: &mut fmt::DebugList<'_, '_>) {}
+    }
+
+    /// Trait implemented for hlists those elements implement [`Display`], used to implement
+    /// [`Display`] for [`DisplayWith`](super::DisplayWith).
+    pub trait DisplayElements {
+        fn fmt_elements<S: Display>(
+            &self,
+            f: &mut fmt::Formatter<'_>,
+            sep: &S,
+            first: bool,
+        ) -> fmt::Result;
+    }
+
+    impl<H, T> DisplayElements for Cons<H, T>
+    where
+        H: Display,
+        T: DisplayElements,
+    {
+        fn fmt_elements<S: Display>(
+            &self,
+            f: &mut fmt::Formatter<'_>,
+            sep: &S,
+            first: bool,
+        ) -> fmt::Result {
+            if !first {
+                write!(f, "{}", sep)?;
+            }
+            Display::fmt(&self.0, f)?;
+            self.1.fmt_elements(f, sep, false)
+        }
+    }
+
+    impl DisplayElements for Nil {
+        fn fmt_elements<S: Display>(
+            &self,
+            _: &mut fmt::Formatter<'_>,
+            _: &S,
+            _: bool,
+        ) -> fmt::Result {
+            Ok(())
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Cons, Nil};
+use crate::{Cons, Func, Nil, Poly};
 
 /// Left fold over a HList.
 ///
@@ -10,6 +10,7 @@ use crate::{Cons, Nil};
 /// 2. A single `FnMut` functions (for homogenous `HList`s)
 /// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
 ///    (for homogenous tails)
+/// 4. A polymorphic function ([`Poly`]) that is called with `(acc, element)` tuples
 ///
 /// See examples for more.
 ///
@@ -61,6 +62,18 @@ use crate::{Cons, Nil};
 /// ]);
 /// assert_eq!(res, 31);
 /// ```
+///
+/// Using [`Poly`] to fold the heterogeneous list:
+///
+/// ```
+/// use minihlist::{hlist, poly_fn, Fold};
+///
+/// let res = hlist![1, 'x', 2].fold(String::new(), poly_fn![
+///     |(acc, i): (String, i32)| -> String { format!("{}{}", acc, i) },
+///     |(acc, c): (String, char)| -> String { format!("{}{}", acc, c) },
+/// ]);
+/// assert_eq!(res, "1x2");
+/// ```
 pub trait Fold<Acc, F> {
     type Output;
 
@@ -124,6 +137,21 @@ where
     }
 }
 
+impl<Acc, H, T, P> Fold<Acc, Poly<P>> for Cons<H, T>
+where
+    P: Func<(Acc, H)>,
+    T: Fold<P::Output, Poly<P>>,
+{
+    type Output = T::Output;
+
+    #[inline]
+    fn fold(self, acc: Acc, Poly(mut p): Poly<P>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let acc = p.call((acc, head));
+        tail.fold(acc, Poly(p))
+    }
+}
+
 impl<Acc, F> Fold<Acc, F> for Nil {
     type Output = Acc;
 
@@ -0,0 +1,124 @@
+use crate::{Cons, Func, Nil, Poly};
+
+/// Call function(s) on every element of a HList in the left-to-right order.
+///
+/// The function(s) type `F` can be:
+/// 1. A `HList` of `FnOnce` functions (one for each element)
+/// 2. A single `FnMut` function (for homogenous `HList`s)
+/// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
+///    (for homogenous tails)
+/// 4. A polymorphic function ([`Poly`]) that can be applied to all elements (its output is
+///    discarded)
+///
+/// See examples for more.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use minihlist::{hlist, ForEach};
+///
+/// let mut out = Vec::new();
+/// let mut len = 0;
+/// hlist![1, "hi"].for_each(hlist![
+///     |i: i32| out.push(i.to_string()),
+///     |s: &str| len = s.len(),
+/// ]);
+/// assert_eq!(out, ["1"]);
+/// assert_eq!(len, 2);
+/// ```
+///
+/// Using `FnMut` for the homogenous list:
+///
+/// ```
+/// use minihlist::{hlist, ForEach};
+///
+/// let mut sum = 0;
+/// hlist![1, 2, 3].for_each(|i| sum += i);
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// Using [`Poly`] for the heterogeneous list:
+///
+/// ```
+/// use minihlist::{hlist, poly_fn, ForEach};
+///
+/// let (mut a, mut b, mut c) = (1, String::from("hi"), vec!['x']);
+/// hlist![&mut a, &mut b, &mut c].for_each(poly_fn![[T: Default] |x: &mut T| -> () {
+///     *x = T::default()
+/// }]);
+/// assert_eq!(a, 0);
+/// assert_eq!(b, "");
+/// assert_eq!(c, []);
+/// ```
+pub trait ForEach<F> {
+    fn for_each(self, f: F);
+}
+
+// F{H,M,T} - function {head,middle,tail} parts
+// {H,M,T}  - {head,middle,tail} of the hlist
+impl<FH, FM, FT, H, M, T> ForEach<Cons<FH, Cons<FM, FT>>> for Cons<H, Cons<M, T>>
+where
+    FH: FnOnce(H),
+    Cons<M, T>: ForEach<Cons<FM, FT>>,
+{
+    #[inline]
+    fn for_each(self, Cons(fh, ft): Cons<FH, Cons<FM, FT>>) {
+        let Cons(head, tail) = self;
+        fh(head);
+        tail.for_each(ft)
+    }
+}
+
+impl<H, F> ForEach<Cons<F, Nil>> for Cons<H, Nil>
+where
+    F: FnOnce(H),
+{
+    #[inline]
+    fn for_each(self, Cons(f, _): Cons<F, Nil>) {
+        let Cons(head, _) = self;
+        f(head)
+    }
+}
+
+impl<H, T, F> ForEach<F> for Cons<H, T>
+where
+    F: FnMut(H),
+    T: ForEach<F>,
+{
+    #[inline]
+    fn for_each(self, mut f: F) {
+        let Cons(head, tail) = self;
+        f(head);
+        tail.for_each(f)
+    }
+}
+
+impl<H, T0, T1, F> ForEach<Cons<F, Nil>> for Cons<H, Cons<T0, T1>>
+where
+    Self: ForEach<F>,
+{
+    #[inline]
+    fn for_each(self, Cons(f, _): Cons<F, Nil>) {
+        self.for_each(f)
+    }
+}
+
+impl<H, T, P> ForEach<Poly<P>> for Cons<H, T>
+where
+    P: Func<H>,
+    T: ForEach<Poly<P>>,
+{
+    #[inline]
+    fn for_each(self, Poly(mut p): Poly<P>) {
+        let Cons(head, tail) = self;
+        p.call(head);
+        tail.for_each(Poly(p))
+    }
+}
+
+impl<F> ForEach<F> for Nil {
+    #[inline]
+    fn for_each(self, _: F) {}
+}
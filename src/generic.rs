@@ -0,0 +1,355 @@
+use crate::{Cons, Nil};
+
+/// Conversion between a type and its generic representation — a HList of its fields.
+///
+/// This allows to use HList operations (like [`Map`](crate::Map) or [`Fold`](crate::Fold)) on
+/// structs. Use [`derive_generic!`](crate::derive_generic) to implement this trait for your
+/// structs.
+///
+/// HLists are their own representations.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{derive_generic, hlist, Generic};
+///
+/// derive_generic! {
+///     #[derive(Debug, PartialEq)]
+///     struct Point {
+///         x: i32,
+///         y: i32,
+///     }
+/// }
+///
+/// let repr = Point { x: 1, y: 2 }.into_repr();
+/// assert_eq!(repr, hlist![1, 2]);
+/// assert_eq!(Point::from_repr(repr), Point { x: 1, y: 2 });
+/// ```
+pub trait Generic {
+    /// HList of the fields of `Self`, in order of declaration.
+    type Repr;
+
+    fn into_repr(self) -> Self::Repr;
+
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+impl<H, T> Generic for Cons<H, T> {
+    type Repr = Self;
+
+    #[inline]
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+
+    #[inline]
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl Generic for Nil {
+    type Repr = Self;
+
+    #[inline]
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+
+    #[inline]
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+/// Converts a value into another type with the same generic representation.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{convert_from, derive_generic, hlist, HList};
+///
+/// derive_generic! {
+///     struct NewUser<'a> {
+///         name: &'a str,
+///         age: u32,
+///     }
+/// }
+///
+/// derive_generic! {
+///     #[derive(Debug, PartialEq)]
+///     struct User<'a>(&'a str, u32);
+/// }
+///
+/// let user: User = convert_from(NewUser { name: "Ferris", age: 12 });
+/// assert_eq!(user, User("Ferris", 12));
+///
+/// let list: HList![&str, u32] = convert_from(user);
+/// assert_eq!(list, hlist!["Ferris", 12]);
+/// ```
+pub fn convert_from<Src, Dst>(src: Src) -> Dst
+where
+    Src: Generic,
+    Dst: Generic<Repr = Src::Repr>,
+{
+    Dst::from_repr(src.into_repr())
+}
+
+/// Defines a struct and implements [`Generic`](crate::Generic) for it.
+///
+/// Named, tuple and unit structs are supported. Generic parameters (lifetimes and types) are
+/// supported, but they can't have bounds or defaults.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{derive_generic, hlist, Generic, Map};
+///
+/// derive_generic! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Pair<A, B>(pub A, pub B);
+/// }
+///
+/// derive_generic! {
+///     #[derive(Debug, PartialEq)]
+///     struct Unit;
+/// }
+///
+/// let pair = Pair::from_repr(Pair(1, "x").into_repr().map(hlist![|i: i32| i * 2, str::len]));
+/// assert_eq!(pair, Pair(2, 1));
+///
+/// assert_eq!(Unit.into_repr(), hlist![]);
+/// ```
+#[macro_export]
+macro_rules! derive_generic {
+    (
+        $( #[$attr:meta] )*
+        $vis:vis struct $name:ident $( < $( $gen:tt ),* $(,)? > )? {
+            $( $( #[$fattr:meta] )* $fvis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        $( #[$attr] )*
+        $vis struct $name $( < $( $gen ),* > )? {
+            $( $( #[$fattr] )* $fvis $field : $ty ),*
+        }
+
+        impl $( < $( $gen ),* > )? $crate::Generic for $name $( < $( $gen ),* > )? {
+            type Repr = $crate::HList![$( $ty ),*];
+
+            fn into_repr(self) -> Self::Repr {
+                let $name { $( $field ),* } = self;
+                $crate::hlist![$( $field ),*]
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                let $crate::hpat![$( $field ),*] = repr;
+                $name { $( $field ),* }
+            }
+        }
+    };
+    (
+        $( #[$attr:meta] )*
+        $vis:vis struct $name:ident $( < $( $gen:tt ),* $(,)? > )? (
+            $( $( #[$fattr:meta] )* $fvis:vis $ty:ty ),* $(,)?
+        );
+    ) => {
+        $( #[$attr] )*
+        $vis struct $name $( < $( $gen ),* > )? (
+            $( $( #[$fattr] )* $fvis $ty ),*
+        );
+
+        $crate::derive_generic! {
+            @tuple
+            [$name] [$( < $( $gen ),* > )?]
+            []
+            [f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 f10 f11 f12 f13 f14 f15 f16 f17 f18 f19 f20 f21 f22 f23]
+            $( $ty, )*
+        }
+    };
+    (
+        $( #[$attr:meta] )*
+        $vis:vis struct $name:ident;
+    ) => {
+        $( #[$attr] )*
+        $vis struct $name;
+
+        impl $crate::Generic for $name {
+            type Repr = $crate::Nil;
+
+            fn into_repr(self) -> Self::Repr {
+                $crate::Nil
+            }
+
+            fn from_repr(_: Self::Repr) -> Self {
+                $name
+            }
+        }
+    };
+    // Assign names to the fields of a tuple struct
+    (
+        @tuple [$name:ident] [$( $gen:tt )*]
+        [$( $field:ident : $fty:ty, )*]
+        [$next:ident $( $pool:ident )*]
+        $ty:ty, $( $rest:ty, )*
+    ) => {
+        $crate::derive_generic! {
+            @tuple [$name] [$( $gen )*]
+            [$( $field : $fty, )* $next : $ty,]
+            [$( $pool )*]
+            $( $rest, )*
+        }
+    };
+    (
+        @tuple [$name:ident] [$( $gen:tt )*]
+        [$( $field:ident : $ty:ty, )*]
+        [$( $pool:ident )*]
+    ) => {
+        impl $( $gen )* $crate::Generic for $name $( $gen )* {
+            type Repr = $crate::HList![$( $ty ),*];
+
+            fn into_repr(self) -> Self::Repr {
+                let $name($( $field ),*) = self;
+                $crate::hlist![$( $field ),*]
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                let $crate::hpat![$( $field ),*] = repr;
+                $name($( $field ),*)
+            }
+        }
+    };
+}
+
+/// Conversion between a type and its labelled generic representation — a record (HList of
+/// [`Field`](crate::Field)s) of its fields.
+///
+/// This is like [`Generic`], but fields are tagged with their names, which allows to convert
+/// between structs with different field order (see [`Transmogrify`](crate::Transmogrify)). Use
+/// [`derive_labelled_generic!`](crate::derive_labelled_generic) to implement this trait for your
+/// structs.
+///
+/// HLists are their own representations.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{derive_labelled_generic, labels, record, LabelledGeneric};
+///
+/// labels!(x, y);
+///
+/// derive_labelled_generic! {
+///     #[derive(Debug, PartialEq)]
+///     struct Point {
+///         x: i32,
+///         y: i32,
+///     }
+/// }
+///
+/// let repr = Point { x: 1, y: 2 }.into_labelled();
+/// assert_eq!(repr, record! { x: 1, y: 2 });
+/// assert_eq!(Point::from_labelled(repr), Point { x: 1, y: 2 });
+/// ```
+pub trait LabelledGeneric {
+    /// Record of the fields of `Self`, in order of declaration.
+    type Repr;
+
+    fn into_labelled(self) -> Self::Repr;
+
+    fn from_labelled(repr: Self::Repr) -> Self;
+}
+
+impl<H, T> LabelledGeneric for Cons<H, T> {
+    type Repr = Self;
+
+    #[inline]
+    fn into_labelled(self) -> Self::Repr {
+        self
+    }
+
+    #[inline]
+    fn from_labelled(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl LabelledGeneric for Nil {
+    type Repr = Self;
+
+    #[inline]
+    fn into_labelled(self) -> Self::Repr {
+        self
+    }
+
+    #[inline]
+    fn from_labelled(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+/// Defines a struct and implements [`LabelledGeneric`](crate::LabelledGeneric) for it.
+///
+/// Only named structs are supported. A label (see [`labels!`](crate::labels)) with the same name
+/// must be in scope for every field (and be at least as visible as the struct). Generic
+/// parameters (lifetimes and types) are supported, but they can't have bounds or defaults.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{derive_labelled_generic, labels, record, LabelledGeneric};
+///
+/// labels!(pub name, age);
+///
+/// derive_labelled_generic! {
+///     #[derive(Debug, PartialEq)]
+///     pub(crate) struct User<'a> {
+///         pub name: &'a str,
+///         age: u32,
+///     }
+/// }
+///
+/// let user = User::from_labelled(record! { name: "Ferris", age: 12 });
+/// assert_eq!(user, User { name: "Ferris", age: 12 });
+/// ```
+#[macro_export]
+macro_rules! derive_labelled_generic {
+    (
+        $( #[$attr:meta] )*
+        $vis:vis struct $name:ident $( < $( $gen:tt ),* $(,)? > )? {
+            $( $( #[$fattr:meta] )* $fvis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        $( #[$attr] )*
+        $vis struct $name $( < $( $gen ),* > )? {
+            $( $( #[$fattr] )* $fvis $field : $ty ),*
+        }
+
+        impl $( < $( $gen ),* > )? $crate::LabelledGeneric for $name $( < $( $gen ),* > )? {
+            type Repr = $crate::Record! { $( $field : $ty ),* };
+
+            fn into_labelled(self) -> Self::Repr {
+                let $name { $( $field ),* } = self;
+                $crate::record! { $( $field : $field ),* }
+            }
+
+            fn from_labelled(repr: Self::Repr) -> Self {
+                let $crate::hpat![$( $field ),*] = repr;
+                $name { $( $field : $field.value ),* }
+            }
+        }
+    };
+}
+
+#[test]
+fn tuple_struct_roundtrip() {
+    use crate::hlist;
+
+    derive_generic! {
+        #[derive(Debug, PartialEq)]
+        struct Many(u8, u16, u32, u64, i8, i16, i32, i64);
+    }
+
+    let many = Many(1, 2, 3, 4, 5, 6, 7, 8);
+    let repr = many.into_repr();
+    assert_eq!(repr, hlist![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(Many::from_repr(repr), Many(1, 2, 3, 4, 5, 6, 7, 8));
+}
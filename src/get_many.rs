@@ -0,0 +1,129 @@
+use crate::{Cons, Nil, Sculpt, ToMut, ToRef};
+
+/// Type-directed borrow of multiple elements of a hlist.
+///
+/// This borrows the elements of types listed in `Target`, returning a hlist of references, e.g.
+/// `list.get_many::<HList![A, B], _>()` returns `HList![&A, &B]`.
+///
+/// `Indices` is a HList of type-level indices of the elements (see [`succnum`](crate::succnum)),
+/// it's inferred just like with [`Get`](crate::Get). Just like with `Get`, the types must be
+/// unique in the list.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, hpat, HList};
+///
+/// let list = hlist![1, 'x', "hi"];
+///
+/// let hpat![s, i] = list.get_many::<HList![&str, i32], _>();
+/// assert_eq!((s, i), (&"hi", &1));
+/// ```
+pub trait GetMany<'a, Target: ToRef<'a>, Indices> {
+    fn get_many(&'a self) -> Target::Output;
+}
+
+impl<'a, L, Target, Indices> GetMany<'a, Target, Indices> for L
+where
+    L: ToRef<'a>,
+    Target: ToRef<'a>,
+    L::Output: Sculpt<Target::Output, Indices>,
+{
+    #[inline]
+    fn get_many(&'a self) -> Target::Output {
+        let (target, _) = self.to_ref().sculpt();
+        target
+    }
+}
+
+/// Type-directed mutable borrow of multiple elements of a hlist.
+///
+/// This is like [`GetMany`], but it returns mutable references, e.g.
+/// `list.get_many_mut::<HList![A, B], _>()` returns `HList![&mut A, &mut B]`. Since the types
+/// must be unique in the list, the references are guaranteed to be disjoint.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, hpat, HList};
+///
+/// struct Config { verbose: bool }
+/// struct Cache { hits: u32 }
+///
+/// let mut state = hlist![Config { verbose: true }, "app", Cache { hits: 0 }];
+///
+/// let hpat![cache, config] = state.get_many_mut::<HList![Cache, Config], _>();
+/// if config.verbose {
+///     cache.hits += 1;
+/// }
+/// config.verbose = false;
+///
+/// let hpat![config, _, cache] = state;
+/// assert!(!config.verbose);
+/// assert_eq!(cache.hits, 1);
+/// ```
+///
+/// It's a compile error to borrow the same element twice:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, HList};
+///
+/// let mut list = hlist![1, 'x'];
+/// let _ = list.get_many_mut::<HList![i32, i32], _>();
+/// ```
+pub trait GetManyMut<'a, Target: ToMut<'a>, Indices> {
+    fn get_many_mut(&'a mut self) -> Target::Output;
+}
+
+impl<'a, L, Target, Indices> GetManyMut<'a, Target, Indices> for L
+where
+    L: ToMut<'a>,
+    Target: ToMut<'a>,
+    L::Output: Sculpt<Target::Output, Indices>,
+{
+    #[inline]
+    fn get_many_mut(&'a mut self) -> Target::Output {
+        let (target, _) = self.to_mut().sculpt();
+        target
+    }
+}
+
+impl<H, T> Cons<H, T> {
+    /// Borrows the elements of types listed in `Target`. See [`GetMany`] for more.
+    pub fn get_many<'a, Target, Indices>(&'a self) -> <Target as ToRef<'a>>::Output
+    where
+        Self: GetMany<'a, Target, Indices>,
+        Target: ToRef<'a>,
+    {
+        GetMany::get_many(self)
+    }
+
+    /// Mutably borrows the elements of types listed in `Target`. See [`GetManyMut`] for more.
+    pub fn get_many_mut<'a, Target, Indices>(&'a mut self) -> <Target as ToMut<'a>>::Output
+    where
+        Self: GetManyMut<'a, Target, Indices>,
+        Target: ToMut<'a>,
+    {
+        GetManyMut::get_many_mut(self)
+    }
+}
+
+impl Nil {
+    /// Borrows the elements of types listed in `Target`. See [`GetMany`] for more.
+    pub fn get_many<'a, Target, Indices>(&'a self) -> <Target as ToRef<'a>>::Output
+    where
+        Self: GetMany<'a, Target, Indices>,
+        Target: ToRef<'a>,
+    {
+        GetMany::get_many(self)
+    }
+
+    /// Mutably borrows the elements of types listed in `Target`. See [`GetManyMut`] for more.
+    pub fn get_many_mut<'a, Target, Indices>(&'a mut self) -> <Target as ToMut<'a>>::Output
+    where
+        Self: GetManyMut<'a, Target, Indices>,
+        Target: ToMut<'a>,
+    {
+        GetManyMut::get_many_mut(self)
+    }
+}
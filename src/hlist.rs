@@ -1,4 +1,4 @@
-use crate::{Cons, Nil, Rev};
+use crate::{Cons, DisplayWith, Nil, Rev};
 
 /// A marker trait that `Nil` and `Cons<H, T>` satisfies.
 /// Not currently used to enforce proper hlists, although this may change.
@@ -32,6 +32,22 @@ pub trait HList: Sized + Rev /* <-- Anyone knows why I've added this bound here?
     fn push<N>(self, item: N) -> Cons<N, Self> {
         Cons(item, self)
     }
+
+    /// Returns an object that implements [`Display`] for lists those elements all implement
+    /// [`Display`]. Elements are separated by `sep`.
+    ///
+    /// ```
+    /// use minihlist::{hlist, HList};
+    ///
+    /// assert_eq!(hlist![1, "x", 'c'].display_with(", ").to_string(), "1, x, c");
+    /// assert_eq!(format!("{:02}", hlist![1, 2].display_with('-')), "01-02");
+    /// assert_eq!(hlist![].display_with(", ").to_string(), "");
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
+    fn display_with<S>(&self, sep: S) -> DisplayWith<'_, Self, S> {
+        DisplayWith::new(self, sep)
+    }
 }
 
 impl HList for Nil {
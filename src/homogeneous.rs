@@ -0,0 +1,435 @@
+use crate::{Cons, IntoArray, Nil, ToRef};
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+};
+
+/// HList those elements are all of the same type `T`.
+///
+/// This trait provides collection-like methods for homogeneous lists. It complements `FnMut`
+/// modes of [`Map`](crate::Map) and [`Fold`](crate::Fold) in cases where the element type
+/// doesn't change. By-value iteration is provided by the `IntoIterator` impl, runtime indexing —
+/// by `Index<usize>` impl.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Homogeneous};
+///
+/// let mut list = hlist![1, 2, 3];
+///
+/// for x in list.iter_mut() {
+///     *x *= 10;
+/// }
+///
+/// assert_eq!(list.iter().sum::<i32>(), 60);
+/// assert_eq!(list.nth(1), Some(&20));
+/// assert_eq!(list.nth(3), None);
+/// assert_eq!(list[2], 30);
+///
+/// assert_eq!(list.as_array(), [&10, &20, &30]);
+/// assert_eq!(list.to_vec(), [10, 20, 30]);
+///
+/// let strings: Vec<String> = list.into_iter().map(|x| x.to_string()).collect();
+/// assert_eq!(strings, ["10", "20", "30"]);
+/// ```
+///
+/// It's a compile error to use these methods on heterogeneous lists:
+///
+/// ```compile_fail,E0599
+/// use minihlist::{hlist, Homogeneous};
+///
+/// let list = hlist![1, 'x'];
+/// let _ = list.iter();
+/// ```
+pub trait Homogeneous<T>: private::Elements<T> + Sized {
+    /// Borrows the element at index `i` or returns `None` if it's out of bounds.
+    #[inline]
+    fn nth(&self, i: usize) -> Option<&T> {
+        self.iter().nth(i)
+    }
+
+    /// Mutably borrows the element at index `i` or returns `None` if it's out of bounds.
+    #[inline]
+    fn nth_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.iter_mut().nth(i)
+    }
+
+    /// Returns an iterator over the elements of the list.
+    #[inline]
+    fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator that allows modifying the elements of the list.
+    #[inline]
+    fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self)
+    }
+
+    /// Returns an array of references to the elements of the list.
+    #[inline]
+    fn as_array<'a>(&'a self) -> <Self::Output as IntoArray<&'a T>>::Array
+    where
+        Self: ToRef<'a>,
+        Self::Output: IntoArray<&'a T>,
+    {
+        self.to_ref().into_array()
+    }
+
+    /// Moves the elements of the list into a `Vec`.
+    #[inline]
+    fn to_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(Self::LEN);
+        self.push_into(&mut vec);
+        vec
+    }
+}
+
+impl<T, Tl> Homogeneous<T> for Cons<T, Tl> where Tl: Homogeneous<T> {}
+
+impl<T> Homogeneous<T> for Nil {}
+
+impl<T, Tl> Index<usize> for Cons<T, Tl>
+where
+    Self: Homogeneous<T>,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        match Homogeneous::nth(self, i) {
+            Some(x) => x,
+            None => out_of_bounds(<Self as private::Elements<T>>::LEN, i),
+        }
+    }
+}
+
+impl<T, Tl> IndexMut<usize> for Cons<T, Tl>
+where
+    Self: Homogeneous<T>,
+{
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match Homogeneous::nth_mut(self, i) {
+            Some(x) => x,
+            None => out_of_bounds(<Self as private::Elements<T>>::LEN, i),
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn out_of_bounds(len: usize, i: usize) -> ! {
+    panic!(
+        "index out of bounds: the len is {} but the index is {}",
+        len, i
+    )
+}
+
+impl<T, Tl> IntoIterator for Cons<T, Tl>
+where
+    Self: Homogeneous<T>,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T, Tl> IntoIterator for &'a Cons<T, Tl>
+where
+    Cons<T, Tl>: Homogeneous<T>,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, Tl> IntoIterator for &'a mut Cons<T, Tl>
+where
+    Cons<T, Tl>: Homogeneous<T>,
+{
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the elements of a homogeneous hlist.
+///
+/// This struct is created by the [`Homogeneous::iter`] and [`IterDyn::iter_dyn`](crate::IterDyn)
+/// methods.
+pub struct Iter<'a, T: ?Sized> {
+    rest: &'a dyn private::Split<T>,
+    len: usize,
+}
+
+impl<'a, T: ?Sized> Iter<'a, T> {
+    pub(crate) fn new(list: &'a dyn private::Split<T>) -> Self {
+        Self {
+            len: list.count(),
+            rest: list,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.rest.split()?;
+        self.rest = tail;
+        self.len -= 1;
+        Some(head)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Iter").field("len", &self.len).finish()
+    }
+}
+
+/// Iterator over mutable references to the elements of a homogeneous hlist.
+///
+/// This struct is created by the [`Homogeneous::iter_mut`] and
+/// [`IterDyn::iter_dyn_mut`](crate::IterDyn) methods.
+pub struct IterMut<'a, T: ?Sized> {
+    rest: Option<&'a mut dyn private::Split<T>>,
+    len: usize,
+}
+
+impl<'a, T: ?Sized> IterMut<'a, T> {
+    pub(crate) fn new(list: &'a mut dyn private::Split<T>) -> Self {
+        Self {
+            len: list.count(),
+            rest: Some(list),
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.rest.take()?.split_mut()?;
+        self.rest = Some(tail);
+        self.len -= 1;
+        Some(head)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T: ?Sized> FusedIterator for IterMut<'_, T> {}
+
+impl<T: ?Sized> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterMut").field("len", &self.len).finish()
+    }
+}
+
+/// By-value iterator over the elements of a homogeneous hlist.
+///
+/// This struct is created by the `into_iter` method of homogeneous lists (provided by the
+/// `IntoIterator` trait).
+pub struct IntoIter<T, L>
+where
+    L: Homogeneous<T>,
+{
+    rest: L::Options,
+    front: usize,
+    len: usize,
+}
+
+impl<T, L> IntoIter<T, L>
+where
+    L: Homogeneous<T>,
+{
+    fn new(list: L) -> Self {
+        Self {
+            rest: list.into_options(),
+            front: 0,
+            len: L::LEN,
+        }
+    }
+}
+
+impl<T, L> Iterator for IntoIter<T, L>
+where
+    L: Homogeneous<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = IterMut::new(&mut self.rest).nth(self.front)?.take();
+        self.front += 1;
+        self.len -= 1;
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, L> ExactSizeIterator for IntoIter<T, L> where L: Homogeneous<T> {}
+
+impl<T, L> FusedIterator for IntoIter<T, L> where L: Homogeneous<T> {}
+
+impl<T, L> fmt::Debug for IntoIter<T, L>
+where
+    L: Homogeneous<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoIter").field("len", &self.len).finish()
+    }
+}
+
+pub(crate) mod private {
+    use crate::{Cons, DynCast, Nil};
+
+    /// Object-safe trait implemented for hlists those elements can all be borrowed as `T`, used
+    /// to implement iterators without slices.
+    ///
+    /// `T` is either the type of all elements (see [`Homogeneous`](super::Homogeneous)) or a
+    /// trait object (see [`IterDyn`](crate::IterDyn)).
+    pub trait Split<T: ?Sized> {
+        /// Number of elements
+        fn count(&self) -> usize;
+
+        fn split(&self) -> Option<(&T, &dyn Split<T>)>;
+
+        fn split_mut(&mut self) -> Option<(&mut T, &mut dyn Split<T>)>;
+    }
+
+    impl<H, Tl, T> Split<T> for Cons<H, Tl>
+    where
+        T: ?Sized + DynCast<H>,
+        Tl: Split<T>,
+    {
+        fn count(&self) -> usize {
+            1 + self.1.count()
+        }
+
+        fn split(&self) -> Option<(&T, &dyn Split<T>)> {
+            Some((T::cast_ref(&self.0), &self.1))
+        }
+
+        fn split_mut(&mut self) -> Option<(&mut T, &mut dyn Split<T>)> {
+            Some((T::cast_mut(&mut self.0), &mut self.1))
+        }
+    }
+
+    impl<T: ?Sized> Split<T> for Nil {
+        fn count(&self) -> usize {
+            0
+        }
+
+        fn split(&self) -> Option<(&T, &dyn Split<T>)> {
+            None
+        }
+
+        fn split_mut(&mut self) -> Option<(&mut T, &mut dyn Split<T>)> {
+            None
+        }
+    }
+
+    /// Trait implemented for homogeneous hlists, used to move their elements.
+    pub trait Elements<T>: Split<T> {
+        /// Number of elements
+        const LEN: usize;
+
+        /// The same list with all elements wrapped in `Option`, so they can be moved out one by
+        /// one
+        type Options: Split<Option<T>>;
+
+        fn push_into(self, vec: &mut Vec<T>);
+
+        fn into_options(self) -> Self::Options;
+    }
+
+    impl<T, Tl> Elements<T> for Cons<T, Tl>
+    where
+        Tl: Elements<T>,
+    {
+        const LEN: usize = 1 + Tl::LEN;
+
+        type Options = Cons<Option<T>, Tl::Options>;
+
+        fn push_into(self, vec: &mut Vec<T>) {
+            let Cons(head, tail) = self;
+            vec.push(head);
+            tail.push_into(vec);
+        }
+
+        fn into_options(self) -> Self::Options {
+            let Cons(head, tail) = self;
+            Cons(Some(head), tail.into_options())
+        }
+    }
+
+    impl<T> Elements<T> for Nil {
+        const LEN: usize = 0;
+
+        type Options = Nil;
+
+        fn push_into(self, _: &mut Vec<T>) {}
+
+        fn into_options(self) -> Self::Options {
+            Nil
+        }
+    }
+}
+
+#[test]
+fn iter_len() {
+    use crate::hlist;
+
+    let mut list = hlist![1, 2, 3];
+
+    let mut iter = list.iter();
+    assert_eq!(iter.len(), 3);
+    iter.next();
+    assert_eq!(iter.len(), 2);
+
+    for x in &mut list {
+        *x += 1;
+    }
+    assert_eq!((&list).into_iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+
+    let mut iter = hlist![String::from("a"), String::from("b")].into_iter();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next().as_deref(), Some("b"));
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,187 @@
+use crate::{homogeneous::private::Split, Cons, Iter, IterMut, Nil};
+use core::{
+    any::Any,
+    fmt::{Debug, Display},
+};
+
+/// Borrowing of `T` as `Self`.
+///
+/// This trait is implemented for every `T` (as `T` itself) and for trait objects those traits `T`
+/// implements (e.g. `dyn Debug` for all `T: Debug`). It's used by [`IterDyn`].
+///
+/// Implementations for trait objects are generated by [`impl_dyn_cast!`](crate::impl_dyn_cast),
+/// this crate provides them for `dyn Any`, `dyn Debug` and `dyn Display`.
+pub trait DynCast<T> {
+    fn cast_ref(value: &T) -> &Self;
+
+    fn cast_mut(value: &mut T) -> &mut Self;
+}
+
+impl<T> DynCast<T> for T {
+    #[inline]
+    fn cast_ref(value: &T) -> &Self {
+        value
+    }
+
+    #[inline]
+    fn cast_mut(value: &mut T) -> &mut Self {
+        value
+    }
+}
+
+/// Implements [`DynCast`](crate::DynCast) for a trait object, so it can be used with
+/// [`IterDyn`](crate::IterDyn).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, impl_dyn_cast};
+///
+/// trait Plugin {
+///     fn name(&self) -> String;
+/// }
+///
+/// impl_dyn_cast!(Plugin);
+///
+/// struct Auth;
+/// struct Cache(u32);
+///
+/// impl Plugin for Auth {
+///     fn name(&self) -> String {
+///         "auth".to_owned()
+///     }
+/// }
+///
+/// impl Plugin for Cache {
+///     fn name(&self) -> String {
+///         format!("cache({})", self.0)
+///     }
+/// }
+///
+/// let plugins = hlist![Auth, Cache(16)];
+/// let names: Vec<_> = plugins.iter_dyn::<dyn Plugin>().map(|p| p.name()).collect();
+/// assert_eq!(names, ["auth", "cache(16)"]);
+/// ```
+#[macro_export]
+macro_rules! impl_dyn_cast {
+    ($( $trait:path ),+ $(,)?) => {
+        $(
+            impl<'a, T: $trait + 'a> $crate::DynCast<T> for dyn $trait + 'a {
+                #[inline]
+                fn cast_ref(value: &T) -> &Self {
+                    value
+                }
+
+                #[inline]
+                fn cast_mut(value: &mut T) -> &mut Self {
+                    value
+                }
+            }
+        )+
+    };
+}
+
+impl_dyn_cast!(Any, Debug, Display);
+
+/// Iteration over the elements of a hlist as trait objects.
+///
+/// This allows to iterate over elements of a heterogeneous list as `&D`/`&mut D`, where `D` is a
+/// trait object (e.g. `dyn Debug`) that all elements can be borrowed as (see [`DynCast`]). It's a
+/// compile error if some element doesn't implement the trait.
+///
+/// Since `D` can't be inferred, it's usually passed via the `iter_dyn` and `iter_dyn_mut`
+/// inherent methods.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::hlist;
+/// use std::{any::Any, fmt::Debug};
+///
+/// let mut list = hlist![1, "hi", 'x'];
+///
+/// let debug: Vec<_> = list.iter_dyn::<dyn Debug>().map(|x| format!("{:?}", x)).collect();
+/// assert_eq!(debug, ["1", "\"hi\"", "'x'"]);
+///
+/// for x in list.iter_dyn_mut::<dyn Any>() {
+///     if let Some(c) = x.downcast_mut::<char>() {
+///         *c = 'y';
+///     }
+/// }
+/// assert_eq!(list, hlist![1, "hi", 'y']);
+/// ```
+///
+/// ```compile_fail,E0277
+/// use minihlist::hlist;
+/// use std::fmt::Display;
+///
+/// struct NoDisplay;
+///
+/// let _ = hlist![1, NoDisplay].iter_dyn::<dyn Display>();
+/// ```
+pub trait IterDyn<D: ?Sized>: Split<D> {
+    /// Returns an iterator over the elements of the list borrowed as `&D`.
+    #[inline]
+    fn iter_dyn(&self) -> Iter<'_, D>
+    where
+        Self: Sized,
+    {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator over the elements of the list borrowed as `&mut D`.
+    #[inline]
+    fn iter_dyn_mut(&mut self) -> IterMut<'_, D>
+    where
+        Self: Sized,
+    {
+        IterMut::new(self)
+    }
+}
+
+impl<L, D> IterDyn<D> for L
+where
+    L: Split<D>,
+    D: ?Sized,
+{
+}
+
+impl<H, T> Cons<H, T> {
+    /// Returns an iterator over the elements of the list borrowed as `&D`. See [`IterDyn`] for
+    /// more.
+    pub fn iter_dyn<D: ?Sized>(&self) -> Iter<'_, D>
+    where
+        Self: IterDyn<D>,
+    {
+        IterDyn::iter_dyn(self)
+    }
+
+    /// Returns an iterator over the elements of the list borrowed as `&mut D`. See [`IterDyn`]
+    /// for more.
+    pub fn iter_dyn_mut<D: ?Sized>(&mut self) -> IterMut<'_, D>
+    where
+        Self: IterDyn<D>,
+    {
+        IterDyn::iter_dyn_mut(self)
+    }
+}
+
+impl Nil {
+    /// Returns an iterator over the elements of the list borrowed as `&D`. See [`IterDyn`] for
+    /// more.
+    pub fn iter_dyn<D: ?Sized>(&self) -> Iter<'_, D>
+    where
+        Self: IterDyn<D>,
+    {
+        IterDyn::iter_dyn(self)
+    }
+
+    /// Returns an iterator over the elements of the list borrowed as `&mut D`. See [`IterDyn`]
+    /// for more.
+    pub fn iter_dyn_mut<D: ?Sized>(&mut self) -> IterMut<'_, D>
+    where
+        Self: IterDyn<D>,
+    {
+        IterDyn::iter_dyn_mut(self)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Cons, Nil};
+
+/// Access to the last element of a hlist.
+///
+/// This is the inverse of [`Append`](crate::Append): `list.unsnoc()` returns a tuple of all
+/// elements but the last one and the last element.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, HList, Last};
+///
+/// let (init, last): (HList![i32, &str], char) = hlist![1, "hi", 'x'].unsnoc();
+/// assert_eq!(init, hlist![1, "hi"]);
+/// assert_eq!(last, 'x');
+///
+/// assert_eq!(hlist![1, "hi", 'x'].last(), 'x');
+/// assert_eq!(hlist![1, "hi", 'x'].init(), hlist![1, "hi"]);
+/// ```
+///
+/// Empty list has no last element:
+///
+/// ```compile_fail,E0599
+/// use minihlist::{hlist, Last};
+///
+/// let _ = hlist![].last();
+/// ```
+pub trait Last {
+    /// Type of the last element
+    type Output;
+
+    /// The list without the last element
+    type Init;
+
+    /// Splits the list, returning tuple of all elements but the last one and the last element.
+    fn unsnoc(self) -> (Self::Init, Self::Output);
+
+    /// Returns the last element of the list.
+    #[inline]
+    fn last(self) -> Self::Output
+    where
+        Self: Sized,
+    {
+        self.unsnoc().1
+    }
+
+    /// Returns the list without the last element.
+    #[inline]
+    fn init(self) -> Self::Init
+    where
+        Self: Sized,
+    {
+        self.unsnoc().0
+    }
+}
+
+impl<H> Last for Cons<H, Nil> {
+    type Output = H;
+    type Init = Nil;
+
+    fn unsnoc(self) -> (Self::Init, Self::Output) {
+        (Nil, self.0)
+    }
+}
+
+impl<H, M, T> Last for Cons<H, Cons<M, T>>
+where
+    Cons<M, T>: Last,
+{
+    type Output = <Cons<M, T> as Last>::Output;
+    type Init = Cons<H, <Cons<M, T> as Last>::Init>;
+
+    fn unsnoc(self) -> (Self::Init, Self::Output) {
+        let Cons(head, tail) = self;
+        let (init, last) = tail.unsnoc();
+        (Cons(head, init), last)
+    }
+}
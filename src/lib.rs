@@ -38,7 +38,9 @@
 //!         _ => unreachable!()
 //!     }
 //!
-//!     //assert_eq!(format!("{:#?}", list), "(\"hello\"), 5, 'c'"); // TODO
+//!     // formatting
+//!     assert_eq!(format!("{:?}", list), "hlist![\"hello\", 5, 'c']");
+//!     assert_eq!(format!("{}", list.display_with(", ")), "hello, 5, c");
 //! }
 //! ```
 //!
@@ -54,6 +56,14 @@
 // $ RUSTDOCFLAGS="--cfg docsrs" cargo doc --open --all-features
 // ```
 #![cfg_attr(all(docsrs, feature = "nightly"), feature(doc_cfg))]
+// Both features are only needed for `Flatten` (see its docs for why narrower ones, like
+// `min_specialization`, don't work). They are incomplete, but `incomplete_features` is only
+// reported for this attribute, so allowing it doesn't hide anything else.
+#![cfg_attr(
+    feature = "nightly",
+    feature(specialization, generic_const_exprs),
+    allow(incomplete_features)
+)]
 #![forbid(unsafe_code)]
 //#![deny(missing_docs)]
 
@@ -62,40 +72,116 @@
 mod local_macros;
 
 mod append;
+mod array;
+mod at;
+mod concat;
+mod coproduct;
 mod extend;
+mod filter;
+mod fmt;
 mod fold;
+mod for_each;
+mod generic;
 mod get;
+mod get_many;
 mod hlist;
+mod homogeneous;
+mod iter_dyn;
+mod last;
 mod map;
+mod poly;
+mod record;
+mod refs;
 mod remove;
 mod rev;
 mod rfold;
+mod sculpt;
+mod sequence;
+mod set;
 mod small;
+mod split;
+mod transmogrify;
+mod try_fold;
+mod try_map;
+mod try_rfold;
+mod try_trait;
 mod tuple;
+mod update;
+mod zip;
+
+#[cfg(feature = "nightly")]
+mod flatten;
 
 #[cfg(feature = "typenum")]
 mod len;
 
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 pub use self::{
-    append::Append, extend::Extend, fold::Fold, get::Get, hlist::HList, map::Map, remove::Remove,
-    rev::Rev, rfold::FoldRight, small::SmallHList, tuple::Tuple,
+    append::Append,
+    array::{FromArray, IntoArray},
+    at::{At, InsertAt, RemoveAt},
+    concat::Concat,
+    coproduct::{CNil, Coproduct, CoproductFold, Inject, Uninject},
+    extend::Extend,
+    filter::{Bool, False, Filter, Predicate, True},
+    fmt::DisplayWith,
+    fold::Fold,
+    for_each::ForEach,
+    generic::{convert_from, Generic, LabelledGeneric},
+    get::Get,
+    get_many::{GetMany, GetManyMut},
+    hlist::HList,
+    homogeneous::{Homogeneous, IntoIter, Iter, IterMut},
+    iter_dyn::{DynCast, IterDyn},
+    last::Last,
+    map::Map,
+    poly::{Func, Poly},
+    record::{Field, GetField, Label, RemoveField},
+    refs::{Cloned, Copied, ToMut, ToRef},
+    remove::Remove,
+    rev::Rev,
+    rfold::FoldRight,
+    sculpt::{Reorder, Sculpt},
+    sequence::{Sequence, Traverse, Validate},
+    set::{Contains, Difference, Intersection, Subset, Union, Unique},
+    small::SmallHList,
+    split::SplitAt,
+    transmogrify::{transmogrify_from, Transmogrify},
+    try_fold::TryFold,
+    try_map::TryMap,
+    try_rfold::TryFoldRight,
+    try_trait::{Residual, Try},
+    tuple::Tuple,
+    update::{Replace, Update},
+    zip::{Unzip, Zip, ZipWith},
 };
 
+#[cfg(feature = "nightly")]
+pub use flatten::Flatten;
+
 #[cfg(feature = "typenum")]
 pub use len::Len;
 
+#[cfg(feature = "serde")]
+pub use serde_impls::Nested;
+
 /// The empty `HList`.
 ///
 /// See [crate documentation](./index.html) for more.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nil;
 
 /// An `HList` with `H` at position 0, and `T` as the rest of the list.
 ///
+/// Note that [`Debug`](core::fmt::Debug) (and `Serialize`, with the `serde` feature) is only
+/// implemented for proper lists, i.e. ones that end with [`Nil`] (e.g. `Cons(1, 2)` doesn't
+/// implement it), since lists are formatted (serialized) flat.
+///
 /// See [crate documentation](./index.html) for more.
-#[derive(Clone, Copy, Eq, Ord, Hash, Debug, Default)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Eq, Ord, Hash, Default)]
 pub struct Cons<H, T>(pub H, pub T);
 
 /// Creates a HList with a single element `head` in it.
@@ -308,8 +394,62 @@ macro_rules! HList {
     () => { $crate::Nil };
 }
 
+/// Type macro for [`Coproduct`]s.
+///
+/// `Coprod![A, B, C]` expands to `Coproduct<A, Coproduct<B, Coproduct<C, CNil>>>`.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{Coprod, Coproduct, Inject};
+///
+/// let _: Coprod![usize, (i32, i32)] = Coproduct::inject((18, 19));
+///
+/// fn test(val: Coprod![i32, &str]) { /* ... */ }
+/// test(Coproduct::inject("hi"));
+/// ```
+#[macro_export]
+macro_rules! Coprod {
+    ($head:ty, $( $tail:tt )*) => {
+        $crate::Coproduct<$head, $crate::Coprod![ $( $tail )* ]>
+    };
+    ($head:ty) => { $crate::Coprod![$head,] /* redirect to previous branch */ };
+    () => { $crate::CNil };
+}
+
 /// Minimalistic analog to crates like `peano` and `typenum`
-mod succnum {
+///
+/// These type-level numbers are used as indices into hlists. Type-directed traits (like [`Get`])
+/// infer them, while position-indexed traits (like [`At`]) expect them to be passed explicitly,
+/// usually via the `U*` aliases:
+///
+/// ```
+/// use minihlist::{hlist, succnum::U1};
+///
+/// let list = hlist![1, 2, 3];
+/// assert_eq!(list.at::<U1>(), &2);
+/// ```
+pub mod succnum {
+    /// Type-level `0`
     pub enum Zero {}
+
+    /// Type-level `I + 1`
     pub struct Succ<I>(I);
+
+    pub type U0 = Zero;
+    pub type U1 = Succ<U0>;
+    pub type U2 = Succ<U1>;
+    pub type U3 = Succ<U2>;
+    pub type U4 = Succ<U3>;
+    pub type U5 = Succ<U4>;
+    pub type U6 = Succ<U5>;
+    pub type U7 = Succ<U6>;
+    pub type U8 = Succ<U7>;
+    pub type U9 = Succ<U8>;
+    pub type U10 = Succ<U9>;
+    pub type U11 = Succ<U10>;
+    pub type U12 = Succ<U11>;
+    pub type U13 = Succ<U12>;
+    pub type U14 = Succ<U13>;
+    pub type U15 = Succ<U14>;
 }
@@ -17,3 +17,15 @@ macro_rules! for_tuples {
     };
     () => {};
 }
+
+/// Analog of the `?` operator for the [`Try`](crate::Try) trait
+macro_rules! tri {
+    ($e:expr) => {
+        match $crate::Try::branch($e) {
+            ::core::ops::ControlFlow::Continue(output) => output,
+            ::core::ops::ControlFlow::Break(residual) => {
+                return $crate::Try::from_residual(residual)
+            }
+        }
+    };
+}
@@ -1,4 +1,4 @@
-use crate::{pure, Cons, Nil};
+use crate::{pure, Cons, Func, Nil, Poly};
 
 /// This trait allows to Map elements of a `HList`.
 ///
@@ -10,6 +10,7 @@ use crate::{pure, Cons, Nil};
 ///    a mutable reference to `FnMut` - `&'_ mut impl FnMut`)
 /// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
 ///    (for homogeneous tails)
+/// 4. A polymorphic function ([`Poly`]) that can be applied to all elements
 ///
 /// See examples for more.
 ///
@@ -50,7 +51,18 @@ use crate::{pure, Cons, Nil};
 /// ]);
 /// assert_eq!(res, hlist![3, "12", "16"]);
 /// ```
-
+///
+/// Using [`Poly`] to map the heterogeneous list:
+///
+/// ```
+/// use minihlist::{hlist, poly_fn, Map};
+///
+/// let res = hlist![1, 'x'].map(poly_fn![
+///     |i: i32| -> i64 { i64::from(i) * 2 },
+///     |c: char| -> char { c.to_ascii_uppercase() },
+/// ]);
+/// assert_eq!(res, hlist![2i64, 'X']);
+/// ```
 pub trait Map<F> {
     type Output;
 
@@ -109,6 +121,20 @@ where
     }
 }
 
+impl<P, H, T> Map<Poly<P>> for Cons<H, T>
+where
+    P: Func<H>,
+    T: Map<Poly<P>>,
+{
+    type Output = Cons<P::Output, T::Output>;
+
+    fn map(self, Poly(mut p): Poly<P>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = p.call(head);
+        Cons(head, tail.map(Poly(p)))
+    }
+}
+
 impl<F> Map<F> for Nil {
     type Output = Nil;
 
@@ -0,0 +1,118 @@
+/// A function that can be called with an argument of type `Input`.
+///
+/// Unlike closures, a single type can implement `Func` for many different inputs, which makes it
+/// possible to apply one "generic" function to all elements of a heterogeneous list. To pass such
+/// a function to [`Map`], [`Fold`], [`FoldRight`] or [`ForEach`], wrap it into [`Poly`].
+///
+/// Functions passed to [`Fold`] and [`FoldRight`] are called with a tuple `(acc, element)`.
+///
+/// Usually it's easier to create a `Func` via the [`poly_fn!`] macro, but it's also possible to
+/// implement it manually, e.g. to keep some state between calls:
+///
+/// ```
+/// use minihlist::{hlist, Func, Map, Poly};
+///
+/// struct Enumerate(usize);
+///
+/// impl<T> Func<T> for Enumerate {
+///     type Output = (usize, T);
+///
+///     fn call(&mut self, input: T) -> Self::Output {
+///         self.0 += 1;
+///         (self.0 - 1, input)
+///     }
+/// }
+///
+/// let res = hlist!['a', "b", 3].map(Poly(Enumerate(0)));
+/// assert_eq!(res, hlist![(0, 'a'), (1, "b"), (2, 3)]);
+/// ```
+///
+/// [`Map`]: crate::Map
+/// [`Fold`]: crate::Fold
+/// [`FoldRight`]: crate::FoldRight
+/// [`ForEach`]: crate::ForEach
+/// [`poly_fn!`]: crate::poly_fn
+pub trait Func<Input> {
+    type Output;
+
+    fn call(&mut self, input: Input) -> Self::Output;
+}
+
+impl<P, Input> Func<Input> for &'_ mut P
+where
+    P: Func<Input>,
+{
+    type Output = P::Output;
+
+    fn call(&mut self, input: Input) -> Self::Output {
+        (**self).call(input)
+    }
+}
+
+/// Wrapper that marks a [`Func`] as a polymorphic function for combinators like
+/// [`Map`](crate::Map) and [`Fold`](crate::Fold).
+///
+/// See [`Func`] and [`poly_fn!`](crate::poly_fn) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Poly<P>(pub P);
+
+/// Creates a polymorphic function ([`Poly`]`<impl `[`Func`]`>`) from a number of cases.
+///
+/// Every case looks like a closure with annotated argument and return types. Generic cases are
+/// prefixed with their generic parameters in square brackets. Cases can't capture variables.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use minihlist::{hlist, poly_fn, Map};
+///
+/// let res = hlist![1, "hi", 'x'].map(poly_fn![
+///     |i: i32| -> String { format!("int {}", i) },
+///     |s: &str| -> String { format!("str {}", s) },
+///     |c: char| -> String { format!("char {}", c) },
+/// ]);
+/// assert_eq!(res, hlist!["int 1", "str hi", "char x"]);
+/// ```
+///
+/// Generic case:
+///
+/// ```
+/// use minihlist::{hlist, poly_fn, Map};
+/// use std::fmt::Display;
+///
+/// let res = hlist![1, "hi", 'x'].map(poly_fn![[T: Display] |x: T| -> String { x.to_string() }]);
+/// assert_eq!(res, hlist!["1", "hi", "x"]);
+/// ```
+///
+/// Folding (note that the argument is a tuple of the accumulator and the element):
+///
+/// ```
+/// use minihlist::{hlist, poly_fn, Fold};
+///
+/// let len = hlist![1, "hi", 'x'].fold(0, poly_fn![
+///     |(acc, _i): (usize, i32)| -> usize { acc + 1 },
+///     |(acc, s): (usize, &str)| -> usize { acc + s.len() },
+///     |(acc, _c): (usize, char)| -> usize { acc + 1 },
+/// ]);
+/// assert_eq!(len, 4);
+/// ```
+#[macro_export]
+macro_rules! poly_fn {
+    (
+        $( $( [ $( $generics:tt )* ] )? |$arg:tt : $input:ty| -> $output:ty $body:block ),+ $(,)?
+    ) => {{
+        struct PolyFn;
+
+        $(
+            impl<$( $( $generics )* )?> $crate::Func<$input> for PolyFn {
+                type Output = $output;
+
+                fn call(&mut self, $arg: $input) -> Self::Output $body
+            }
+        )+
+
+        $crate::Poly(PolyFn)
+    }};
+}
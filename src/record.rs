@@ -0,0 +1,366 @@
+use crate::{
+    succnum::{Succ, Zero},
+    Cons,
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+};
+
+/// Type-level name of a record field.
+///
+/// Labels are usually declared with the [`labels!`](crate::labels) macro.
+pub trait Label {
+    /// Name of the label
+    const NAME: &'static str;
+}
+
+/// Labelled element of a record.
+///
+/// A record is a HList of `Field`s, e.g. `HList![Field<name, String>, Field<age, u32>]`. Records
+/// are usually created by the [`record!`](crate::record) macro and their types are written with
+/// the [`Record!`](crate::Record) macro.
+///
+/// `Field<L, V>` is `V` tagged with a label `L`, so unlike with [`Get`](crate::Get), fields can be
+/// looked up by label even when multiple fields have the same type (see [`GetField`]).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{labels, record, Field, Record};
+///
+/// labels!(name, age);
+///
+/// let ferris: Record! { name: &str, age: u32 } = record! { name: "Ferris", age: 12 };
+/// assert_eq!(format!("{:?}", ferris), r#"hlist![name: "Ferris", age: 12]"#);
+///
+/// let field = Field::<name, _>::new("x");
+/// assert_eq!(field.name(), "name");
+/// assert_eq!(field.value, "x");
+/// ```
+pub struct Field<L, V> {
+    pub value: V,
+    label: PhantomData<L>,
+}
+
+impl<L, V> Field<L, V> {
+    pub const fn new(value: V) -> Self {
+        Self {
+            value,
+            label: PhantomData,
+        }
+    }
+
+    /// Returns the name of the label of this field.
+    pub fn name(&self) -> &'static str
+    where
+        L: Label,
+    {
+        L::NAME
+    }
+
+    pub fn into_value(self) -> V {
+        self.value
+    }
+}
+
+// Manual impls to not require `L: Trait` bounds (labels are uninhabited enums)
+
+impl<L, V: Clone> Clone for Field<L, V> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<L, V: Copy> Copy for Field<L, V> {}
+
+impl<L, V: PartialEq> PartialEq for Field<L, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<L, V: Eq> Eq for Field<L, V> {}
+
+impl<L, V: PartialOrd> PartialOrd for Field<L, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<L, V: Ord> Ord for Field<L, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<L, V: Hash> Hash for Field<L, V> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.value.hash(state)
+    }
+}
+
+impl<L, V: Default> Default for Field<L, V> {
+    fn default() -> Self {
+        Self::new(V::default())
+    }
+}
+
+/// Formats the field as `name: value`.
+impl<L: Label, V: Debug> Debug for Field<L, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", L::NAME)?;
+        self.value.fmt(f)
+    }
+}
+
+/// Search for a record field by label.
+///
+/// `Idx` is a type-level index of the field (see [`succnum`](crate::succnum)), it's inferred just
+/// like with [`Get`](crate::Get). Labels must be unique in the record.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{labels, record, GetField};
+///
+/// labels!(first_name, last_name);
+///
+/// let mut person = record! { first_name: "Ferris", last_name: "Crab" };
+///
+/// assert_eq!(person.get_field::<first_name, _>(), &"Ferris");
+/// assert_eq!(person.get_field::<last_name, _>(), &"Crab");
+///
+/// *person.get_field_mut::<first_name, _>() = "Corro";
+/// let old = person.replace_field::<last_name, _>("Urchin");
+///
+/// assert_eq!(old, "Crab");
+/// assert_eq!(person, record! { first_name: "Corro", last_name: "Urchin" });
+/// ```
+///
+/// It's a compile error to lookup a label that is not in the record:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{labels, record};
+///
+/// labels!(name, age);
+///
+/// let person = record! { name: "Ferris" };
+/// let _ = person.get_field::<age, _>();
+/// ```
+pub trait GetField<L, Idx> {
+    /// Type of the value of the field
+    type Value;
+
+    fn get_field(&self) -> &Self::Value;
+
+    fn get_field_mut(&mut self) -> &mut Self::Value;
+
+    /// Replaces the value of the field, returning the old value.
+    #[inline]
+    fn replace_field(&mut self, value: Self::Value) -> Self::Value {
+        mem::replace(self.get_field_mut(), value)
+    }
+}
+
+impl<L, V, T> GetField<L, Zero> for Cons<Field<L, V>, T> {
+    type Value = V;
+
+    fn get_field(&self) -> &V {
+        &self.0.value
+    }
+
+    fn get_field_mut(&mut self) -> &mut V {
+        &mut self.0.value
+    }
+}
+
+impl<L, H, T, Idx> GetField<L, Succ<Idx>> for Cons<H, T>
+where
+    T: GetField<L, Idx>,
+{
+    type Value = T::Value;
+
+    fn get_field(&self) -> &Self::Value {
+        self.1.get_field()
+    }
+
+    fn get_field_mut(&mut self) -> &mut Self::Value {
+        self.1.get_field_mut()
+    }
+}
+
+/// Search & remove of a record field by label.
+///
+/// This trait is similar to [`GetField`] but instead of borrowing, it allows to remove fields from
+/// a record.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{labels, record, RemoveField};
+///
+/// labels!(name, age);
+///
+/// let (age, rest) = record! { name: "Ferris", age: 12 }.remove_field::<age, _>();
+/// assert_eq!(age, 12);
+/// assert_eq!(rest, record! { name: "Ferris" });
+/// ```
+pub trait RemoveField<L, Idx>: GetField<L, Idx> {
+    type Rest;
+
+    fn remove_field(self) -> (Self::Value, Self::Rest);
+}
+
+impl<L, V, T> RemoveField<L, Zero> for Cons<Field<L, V>, T> {
+    type Rest = T;
+
+    fn remove_field(self) -> (V, Self::Rest) {
+        let Cons(head, tail) = self;
+        (head.value, tail)
+    }
+}
+
+impl<L, H, T, Idx> RemoveField<L, Succ<Idx>> for Cons<H, T>
+where
+    T: RemoveField<L, Idx>,
+{
+    type Rest = Cons<H, T::Rest>;
+
+    fn remove_field(self) -> (Self::Value, Self::Rest) {
+        let Cons(head, tail) = self;
+        let (ret, tail_rest) = tail.remove_field();
+        (ret, Cons(head, tail_rest))
+    }
+}
+
+impl<H, T> Cons<H, T> {
+    /// Borrows the value of the field labelled `L`. See [`GetField`] for more.
+    pub fn get_field<L, Idx>(&self) -> &<Self as GetField<L, Idx>>::Value
+    where
+        Self: GetField<L, Idx>,
+    {
+        GetField::get_field(self)
+    }
+
+    /// Mutably borrows the value of the field labelled `L`. See [`GetField`] for more.
+    pub fn get_field_mut<L, Idx>(&mut self) -> &mut <Self as GetField<L, Idx>>::Value
+    where
+        Self: GetField<L, Idx>,
+    {
+        GetField::get_field_mut(self)
+    }
+
+    /// Replaces the value of the field labelled `L`, returning the old value. See [`GetField`]
+    /// for more.
+    pub fn replace_field<L, Idx>(
+        &mut self,
+        value: <Self as GetField<L, Idx>>::Value,
+    ) -> <Self as GetField<L, Idx>>::Value
+    where
+        Self: GetField<L, Idx>,
+    {
+        GetField::replace_field(self, value)
+    }
+
+    /// Removes the field labelled `L`. See [`RemoveField`] for more.
+    #[allow(clippy::type_complexity)]
+    pub fn remove_field<L, Idx>(
+        self,
+    ) -> (
+        <Self as GetField<L, Idx>>::Value,
+        <Self as RemoveField<L, Idx>>::Rest,
+    )
+    where
+        Self: RemoveField<L, Idx>,
+    {
+        RemoveField::remove_field(self)
+    }
+}
+
+/// Declares record labels.
+///
+/// Each label is an uninhabited enum implementing [`Label`](crate::Label). Labels are named after
+/// fields, so they are lowercase.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{labels, Label};
+///
+/// labels!(name, age);
+///
+/// mod labels {
+///     minihlist::labels!(pub id);
+/// }
+///
+/// assert_eq!(name::NAME, "name");
+/// assert_eq!(labels::id::NAME, "id");
+/// ```
+#[macro_export]
+macro_rules! labels {
+    ($vis:vis $( $label:ident ),+ $(,)?) => {
+        $(
+            #[allow(non_camel_case_types)]
+            $vis enum $label {}
+
+            impl $crate::Label for $label {
+                const NAME: &'static str = stringify!($label);
+            }
+        )+
+    };
+}
+
+/// Creates a record (HList of [`Field`](crate::Field)s).
+///
+/// Labels must be declared with [`labels!`](crate::labels).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, labels, record, Field};
+///
+/// labels!(name, age);
+///
+/// let ferris = record! { name: "Ferris", age: 12 };
+/// assert_eq!(ferris, hlist![Field::<name, _>::new("Ferris"), Field::<age, _>::new(12)]);
+/// ```
+#[macro_export]
+macro_rules! record {
+    ($( $label:ident : $value:expr ),* $(,)?) => {
+        $crate::hlist![$( $crate::Field::<$label, _>::new($value) ),*]
+    };
+}
+
+/// Type of a record (HList of [`Field`](crate::Field)s).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{labels, record, Field, HList, Record};
+///
+/// labels!(name, age);
+///
+/// let _: Record! { name: &str, age: u32 } = record! { name: "Ferris", age: 12 };
+/// let _: HList![Field<name, &str>, Field<age, u32>] = record! { name: "Ferris", age: 12 };
+/// ```
+#[macro_export]
+macro_rules! Record {
+    ($( $label:ident : $ty:ty ),* $(,)?) => {
+        $crate::HList![$( $crate::Field<$label, $ty> ),*]
+    };
+}
+
+#[test]
+fn same_value_types() {
+    labels!(first, second);
+
+    let mut list = record! { first: 1, second: 2 };
+    *list.get_field_mut::<second, _>() += 40;
+
+    assert_eq!(list.get_field::<first, _>(), &1);
+    assert_eq!(list.get_field::<second, _>(), &42);
+}
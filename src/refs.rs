@@ -0,0 +1,169 @@
+use crate::{Cons, Nil};
+
+/// Borrow all elements of a hlist.
+///
+/// This turns `&HList![A, B, C]` into `HList![&A, &B, &C]`, so combinators that consume the list
+/// (like [`Map`](crate::Map) or [`Fold`](crate::Fold)) can be used without destroying it.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Fold, Map, ToRef};
+///
+/// let list = hlist![String::from("hi"), 17];
+///
+/// assert_eq!(list.to_ref(), hlist![&String::from("hi"), &17]);
+///
+/// let lens = list.to_ref().map(hlist![|s: &String| s.len(), |i: &i32| *i as usize]);
+/// assert_eq!(lens, hlist![2, 17]);
+///
+/// let sum = list.to_ref().fold(0, hlist![
+///     |acc, s: &String| acc + s.len(),
+///     |acc, i: &i32| acc + *i as usize,
+/// ]);
+/// assert_eq!(sum, 19);
+///
+/// // the list is still usable
+/// assert_eq!(list, hlist![String::from("hi"), 17]);
+/// ```
+pub trait ToRef<'a> {
+    type Output;
+
+    fn to_ref(&'a self) -> Self::Output;
+}
+
+impl<'a> ToRef<'a> for Nil {
+    type Output = Nil;
+
+    fn to_ref(&'a self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, H, T> ToRef<'a> for Cons<H, T>
+where
+    H: 'a,
+    T: ToRef<'a>,
+{
+    type Output = Cons<&'a H, T::Output>;
+
+    fn to_ref(&'a self) -> Self::Output {
+        Cons(&self.0, self.1.to_ref())
+    }
+}
+
+/// Mutably borrow all elements of a hlist.
+///
+/// This turns `&mut HList![A, B, C]` into `HList![&mut A, &mut B, &mut C]`.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Map, ToMut};
+///
+/// let mut list = hlist![String::from("hi"), 17];
+///
+/// list.to_mut().map(hlist![|s: &mut String| s.push('!'), |i: &mut i32| *i += 1]);
+/// assert_eq!(list, hlist![String::from("hi!"), 18]);
+/// ```
+pub trait ToMut<'a> {
+    type Output;
+
+    fn to_mut(&'a mut self) -> Self::Output;
+}
+
+impl<'a> ToMut<'a> for Nil {
+    type Output = Nil;
+
+    fn to_mut(&'a mut self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, H, T> ToMut<'a> for Cons<H, T>
+where
+    H: 'a,
+    T: ToMut<'a>,
+{
+    type Output = Cons<&'a mut H, T::Output>;
+
+    fn to_mut(&'a mut self) -> Self::Output {
+        Cons(&mut self.0, self.1.to_mut())
+    }
+}
+
+/// Clone all elements of a hlist of references.
+///
+/// This is the inverse of [`ToRef`].
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Cloned, ToRef};
+///
+/// let list = hlist![String::from("hi"), vec![1, 2]];
+/// assert_eq!(list.to_ref().cloned(), list);
+/// ```
+pub trait Cloned {
+    type Output;
+
+    fn cloned(self) -> Self::Output;
+}
+
+impl Cloned for Nil {
+    type Output = Nil;
+
+    fn cloned(self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<H, T> Cloned for Cons<&H, T>
+where
+    H: Clone,
+    T: Cloned,
+{
+    type Output = Cons<H, T::Output>;
+
+    fn cloned(self) -> Self::Output {
+        Cons(self.0.clone(), self.1.cloned())
+    }
+}
+
+/// Copy all elements of a hlist of references.
+///
+/// This is the inverse of [`ToRef`] for lists of `Copy` types.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Copied, ToRef};
+///
+/// let list = hlist![1, 'x', false];
+/// assert_eq!(list.to_ref().copied(), list);
+/// ```
+pub trait Copied {
+    type Output;
+
+    fn copied(self) -> Self::Output;
+}
+
+impl Copied for Nil {
+    type Output = Nil;
+
+    fn copied(self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<H, T> Copied for Cons<&H, T>
+where
+    H: Copy,
+    T: Copied,
+{
+    type Output = Cons<H, T::Output>;
+
+    fn copied(self) -> Self::Output {
+        Cons(*self.0, self.1.copied())
+    }
+}
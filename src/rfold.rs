@@ -1,4 +1,4 @@
-use crate::{Cons, Nil};
+use crate::{Cons, Func, Nil, Poly};
 
 /// Right fold over a HList.
 ///
@@ -10,6 +10,7 @@ use crate::{Cons, Nil};
 /// 2. A single `FnMut` functions (for homogenous `HList`s)
 /// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
 ///    (for homogenous tails)
+/// 4. A polymorphic function ([`Poly`]) that is called with `(acc, element)` tuples
 ///
 /// See examples for more.
 ///
@@ -61,6 +62,18 @@ use crate::{Cons, Nil};
 /// ]);
 /// assert_eq!(res, 45);
 /// ```
+///
+/// Using [`Poly`] to fold the heterogeneous list:
+///
+/// ```
+/// use minihlist::{hlist, poly_fn, FoldRight};
+///
+/// let res = hlist![1, 'x', 2].rfold(String::new(), poly_fn![
+///     |(acc, i): (String, i32)| -> String { format!("{}{}", acc, i) },
+///     |(acc, c): (String, char)| -> String { format!("{}{}", acc, c) },
+/// ]);
+/// assert_eq!(res, "2x1");
+/// ```
 pub trait FoldRight<Acc, F> {
     type Output;
 
@@ -125,6 +138,21 @@ where
     }
 }
 
+impl<Acc, H, T, P, R> FoldRight<Acc, Poly<P>> for Cons<H, T>
+where
+    for<'a> T: FoldRight<Acc, Poly<&'a mut P>, Output = R>,
+    P: Func<(R, H)>,
+{
+    type Output = P::Output;
+
+    #[inline]
+    fn rfold(self, acc: Acc, Poly(mut p): Poly<P>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let acc = tail.rfold(acc, Poly(&mut p));
+        p.call((acc, head))
+    }
+}
+
 impl<Acc, F> FoldRight<Acc, F> for Nil {
     type Output = Acc;
 
@@ -0,0 +1,145 @@
+use crate::{Cons, Nil, Remove};
+
+/// Type-directed extraction of multiple elements from a hlist.
+///
+/// This trait allows to remove several elements from a hlist by type (in any order) in one go.
+/// It returns the `Target` list along with the remainder — elements of `Self` that are not in
+/// `Target`.
+///
+/// `Indices` is a HList of type-level indices of the elements (see [`succnum`](crate::succnum)),
+/// it's inferred just like with [`Get`](crate::Get) and [`Remove`]. Just like with those traits,
+/// the types of extracted elements must be unique in `Self`.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, HList, Sculpt};
+///
+/// let list = hlist![1, 'x', "hi", 2.5];
+///
+/// let (target, rest): (HList![&str, i32], _) = list.sculpt();
+/// assert_eq!(target, hlist!["hi", 1]);
+/// assert_eq!(rest, hlist!['x', 2.5]);
+///
+/// // or with turbofish
+/// let (target, rest) = list.sculpt::<HList![f64, char], _>();
+/// assert_eq!(target, hlist![2.5, 'x']);
+/// assert_eq!(rest, hlist![1, "hi"]);
+/// ```
+///
+/// It's a compile error to sculpt elements that are not in the list:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, HList};
+///
+/// let _ = hlist![1, 'x'].sculpt::<HList![char, bool], _>();
+/// ```
+pub trait Sculpt<Target, Indices> {
+    /// Elements of `Self` that are not in `Target`
+    type Remainder;
+
+    fn sculpt(self) -> (Target, Self::Remainder);
+}
+
+impl<Source> Sculpt<Nil, Nil> for Source {
+    type Remainder = Source;
+
+    #[inline]
+    fn sculpt(self) -> (Nil, Self::Remainder) {
+        (Nil, self)
+    }
+}
+
+// {TH,TT}    - {head,tail} of the target
+// Idx, TIdx  - index of the target head in `Source` and indices of the target tail
+impl<Source, TH, TT, Idx, TIdx> Sculpt<Cons<TH, TT>, Cons<Idx, TIdx>> for Source
+where
+    Source: Remove<Idx, TH>,
+    Source::Rest: Sculpt<TT, TIdx>,
+{
+    type Remainder = <Source::Rest as Sculpt<TT, TIdx>>::Remainder;
+
+    #[inline]
+    fn sculpt(self) -> (Cons<TH, TT>, Self::Remainder) {
+        let (head, rest) = self.remove();
+        let (tail, remainder) = rest.sculpt();
+        (Cons(head, tail), remainder)
+    }
+}
+
+/// Reorder a hlist into a permutation of itself.
+///
+/// This is [`Sculpt`] that leaves no remainder, i.e. it proves at compile time that `Target`
+/// consists of exactly the same elements as `Self` (in any order).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, HList, Reorder};
+///
+/// let list = hlist![1, 'x', "hi"];
+///
+/// let reordered: HList![&str, i32, char] = list.reorder();
+/// assert_eq!(reordered, hlist!["hi", 1, 'x']);
+///
+/// // or with turbofish
+/// assert_eq!(list.reorder::<HList![char, &str, i32], _>(), hlist!['x', "hi", 1]);
+/// ```
+///
+/// It's a compile error to drop elements:
+///
+/// ```compile_fail,E0271
+/// use minihlist::{hlist, HList};
+///
+/// let _ = hlist![1, 'x', "hi"].reorder::<HList![&str, i32], _>();
+/// ```
+pub trait Reorder<Target, Indices> {
+    fn reorder(self) -> Target;
+}
+
+impl<Source, Target, Indices> Reorder<Target, Indices> for Source
+where
+    Source: Sculpt<Target, Indices, Remainder = Nil>,
+{
+    #[inline]
+    fn reorder(self) -> Target {
+        let (target, Nil) = self.sculpt();
+        target
+    }
+}
+
+impl<H, T> Cons<H, T> {
+    /// Extracts the elements of `Target` from the list. See [`Sculpt`] for more.
+    pub fn sculpt<Target, Indices>(self) -> (Target, <Self as Sculpt<Target, Indices>>::Remainder)
+    where
+        Self: Sculpt<Target, Indices>,
+    {
+        Sculpt::sculpt(self)
+    }
+
+    /// Reorders the list into `Target`. See [`Reorder`] for more.
+    pub fn reorder<Target, Indices>(self) -> Target
+    where
+        Self: Reorder<Target, Indices>,
+    {
+        Reorder::reorder(self)
+    }
+}
+
+impl Nil {
+    /// Extracts the elements of `Target` from the list. See [`Sculpt`] for more.
+    pub fn sculpt<Target, Indices>(self) -> (Target, <Self as Sculpt<Target, Indices>>::Remainder)
+    where
+        Self: Sculpt<Target, Indices>,
+    {
+        Sculpt::sculpt(self)
+    }
+
+    /// Reorders the list into `Target`. See [`Reorder`] for more.
+    pub fn reorder<Target, Indices>(self) -> Target
+    where
+        Self: Reorder<Target, Indices>,
+    {
+        Reorder::reorder(self)
+    }
+}
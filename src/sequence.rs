@@ -0,0 +1,170 @@
+use crate::{Cons, Map, Nil, Residual, Try};
+
+/// Turn a HList of [`Try`] types (`Option`, `Result` or `ControlFlow`) inside out.
+///
+/// E.g. `HList![Option<A>, Option<B>]` becomes `Option<HList![A, B]>` and
+/// `HList![Result<A, E>, Result<B, E>]` becomes `Result<HList![A, B], E>`. The first element that
+/// short-circuits (e.g. `None` or `Err(_)`) is returned.
+///
+/// All elements must short-circuit with the same residual (`Res`), e.g. they should all be
+/// `Result<_, E>` with the same error type `E`. See [`Validate`] for a version that collects all
+/// errors.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Sequence};
+///
+/// assert_eq!(hlist![Some(1), Some('x')].sequence(), Some(hlist![1, 'x']));
+/// assert_eq!(hlist![Some(1), None::<char>].sequence(), None);
+///
+/// let list = hlist![Ok(1), Err::<bool, _>("first"), Ok('x'), Err::<u8, _>("second")];
+/// assert_eq!(list.sequence(), Err("first"));
+/// ```
+pub trait Sequence<Res> {
+    type Output: Try<Residual = Res>;
+
+    fn sequence(self) -> Self::Output;
+}
+
+impl<H, T, Res> Sequence<Res> for Cons<H, T>
+where
+    H: Try<Residual = Res>,
+    T: Sequence<Res>,
+    Res: Residual<Cons<H::Output, <T::Output as Try>::Output>>,
+{
+    type Output = Res::TryType;
+
+    fn sequence(self) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = tri!(head);
+        let tail = tri!(tail.sequence());
+        Try::from_output(Cons(head, tail))
+    }
+}
+
+impl<Res> Sequence<Res> for Nil
+where
+    Res: Residual<Nil>,
+{
+    type Output = Res::TryType;
+
+    fn sequence(self) -> Self::Output {
+        Try::from_output(Nil)
+    }
+}
+
+/// Map a HList with function(s) returning [`Try`] types and then [`Sequence`] the result.
+///
+/// The function(s) type `F` follows the same rules as with [`Map`]. Note that unlike
+/// [`TryMap`](crate::TryMap), all functions are called even if one of them short-circuits.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Traverse};
+///
+/// let res = hlist!["1", "x"].traverse(hlist![
+///     |s: &str| s.parse::<i32>().ok(),
+///     |s: &str| s.chars().next(),
+/// ]);
+/// assert_eq!(res, Some(hlist![1, 'x']));
+///
+/// let res = hlist!["1", "2", "three"].traverse(&mut str::parse::<u8>);
+/// assert!(res.is_err());
+/// ```
+pub trait Traverse<F, Res> {
+    type Output: Try<Residual = Res>;
+
+    fn traverse(self, f: F) -> Self::Output;
+}
+
+impl<L, F, Res> Traverse<F, Res> for L
+where
+    L: Map<F>,
+    L::Output: Sequence<Res>,
+{
+    type Output = <L::Output as Sequence<Res>>::Output;
+
+    fn traverse(self, f: F) -> Self::Output {
+        self.map(f).sequence()
+    }
+}
+
+/// Turn a HList of `Result`s inside out, collecting all errors.
+///
+/// This is like [`Sequence`], but instead of stopping at the first `Err`, it collects errors of
+/// all elements into a `Vec`.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Validate};
+///
+/// let list = hlist![Ok::<_, &str>(1), Ok('x')];
+/// assert_eq!(list.validate(), Ok(hlist![1, 'x']));
+///
+/// let list = hlist![Ok(1), Err::<bool, _>("first"), Ok('x'), Err::<u8, _>("second")];
+/// assert_eq!(list.validate(), Err(vec!["first", "second"]));
+/// ```
+pub trait Validate<E> {
+    type Output;
+
+    fn validate(self) -> Result<Self::Output, Vec<E>>;
+}
+
+impl<L, E> Validate<E> for L
+where
+    L: private::ValidateInto<E>,
+{
+    type Output = L::Output;
+
+    fn validate(self) -> Result<Self::Output, Vec<E>> {
+        let mut errors = Vec::new();
+        match self.validate_into(&mut errors) {
+            Some(list) => Ok(list),
+            None => Err(errors),
+        }
+    }
+}
+
+mod private {
+    use crate::{Cons, Nil};
+
+    /// Validates the list left to right, pushing errors into `errors`. Returns `None` if there
+    /// were any errors. Used to implement [`Validate`](super::Validate) without shifting the
+    /// errors vector.
+    pub trait ValidateInto<E> {
+        type Output;
+
+        fn validate_into(self, errors: &mut Vec<E>) -> Option<Self::Output>;
+    }
+
+    impl<H, T, E> ValidateInto<E> for Cons<Result<H, E>, T>
+    where
+        T: ValidateInto<E>,
+    {
+        type Output = Cons<H, T::Output>;
+
+        fn validate_into(self, errors: &mut Vec<E>) -> Option<Self::Output> {
+            let Cons(head, tail) = self;
+            let head = match head {
+                Ok(head) => Some(head),
+                Err(err) => {
+                    errors.push(err);
+                    None
+                }
+            };
+            let tail = tail.validate_into(errors);
+            Some(Cons(head?, tail?))
+        }
+    }
+
+    impl<E> ValidateInto<E> for Nil {
+        type Output = Nil;
+
+        fn validate_into(self, _: &mut Vec<E>) -> Option<Self::Output> {
+            Some(Nil)
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{Cons, HList, Nil};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// Serializes the list as a flat sequence (tuple) of its elements.
+///
+/// I.e. `hlist![1, "x", 'c']` is serialized in exactly the same way as `(1, "x", 'c')`. Use
+/// [`Nested`] to serialize the list as nested pairs.
+///
+/// This is only implemented for proper lists (ones that end with [`Nil`]).
+///
+/// ```
+/// use minihlist::hlist;
+///
+/// let json = serde_json::to_string(&hlist![1, "x", 'c']).unwrap();
+/// assert_eq!(json, r#"[1,"x","c"]"#);
+/// assert_eq!(json, serde_json::to_string(&(1, "x", 'c')).unwrap());
+/// ```
+impl<H, T> Serialize for Cons<H, T>
+where
+    Self: HList + private::SerializeElements,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(Self::LEN)?;
+        private::SerializeElements::serialize_elements(self, &mut tuple)?;
+        tuple.end()
+    }
+}
+
+/// Deserializes the list from a flat sequence (tuple) of its elements.
+///
+/// The sequence must have exactly as many elements as the list.
+///
+/// ```
+/// use minihlist::{hlist, HList};
+///
+/// let list: HList![i32, String, char] = serde_json::from_str(r#"[1,"x","c"]"#).unwrap();
+/// assert_eq!(list, hlist![1, "x", 'c']);
+///
+/// assert!(serde_json::from_str::<HList![i32, String]>(r#"[1]"#).is_err());
+/// assert!(serde_json::from_str::<HList![i32, String]>(r#"[1,"x","c"]"#).is_err());
+/// ```
+impl<'de, H, T> Deserialize<'de> for Cons<H, T>
+where
+    Self: HList + private::DeserializeElements<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(Self::LEN, FlatVisitor::<Self>(PhantomData))
+    }
+}
+
+struct FlatVisitor<L>(PhantomData<L>);
+
+impl<'de, L> de::Visitor<'de> for FlatVisitor<L>
+where
+    L: HList + private::DeserializeElements<'de>,
+{
+    type Value = L;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {} elements", L::LEN)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let list = L::deserialize_elements(&mut seq, 0, &self)?;
+
+        let mut len = L::LEN;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+
+        if len != L::LEN {
+            return Err(de::Error::invalid_length(len, &self));
+        }
+
+        Ok(list)
+    }
+}
+
+/// Wrapper that (de)serializes a hlist as nested pairs.
+///
+/// I.e. `Nested(hlist![1, 2])` is serialized in the same way as `Cons(1, Cons(2, Nil))` would be
+/// if `Cons` and `Nil` derived `Serialize` (this is how hlists were serialized by older versions
+/// of this crate).
+///
+/// ```
+/// use minihlist::{hlist, HList, Nested};
+///
+/// let json = serde_json::to_string(&Nested(hlist![1, 2, 3])).unwrap();
+/// assert_eq!(json, "[1,[2,[3,null]]]");
+///
+/// let Nested(list): Nested<HList![i32, i32, i32]> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(list, hlist![1, 2, 3]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Nested<L>(pub L);
+
+impl<L> Serialize for Nested<L>
+where
+    L: private::SerializeNested,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        private::SerializeNested::serialize_nested(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Nested<Nil> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Nil::deserialize(deserializer).map(Nested)
+    }
+}
+
+impl<'de, H, T> Deserialize<'de> for Nested<Cons<H, T>>
+where
+    H: Deserialize<'de>,
+    Nested<T>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple_struct("Cons", 2, NestedVisitor::<H, T>(PhantomData))
+    }
+}
+
+struct NestedVisitor<H, T>(PhantomData<(H, T)>);
+
+impl<'de, H, T> de::Visitor<'de> for NestedVisitor<H, T>
+where
+    H: Deserialize<'de>,
+    Nested<T>: Deserialize<'de>,
+{
+    type Value = Nested<Cons<H, T>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("tuple struct Cons")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let head = seq
+            .next_element::<H>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let Nested(tail) = seq
+            .next_element::<Nested<T>>()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok(Nested(Cons(head, tail)))
+    }
+}
+
+mod private {
+    use crate::{Cons, Nil};
+    use serde::{
+        de::{self, Deserialize, Expected, SeqAccess},
+        ser::{Serialize, SerializeTuple, SerializeTupleStruct, Serializer},
+    };
+
+    /// Trait implemented for hlists those elements implement [`Serialize`], used to serialize
+    /// [`Cons`] as a flat tuple.
+    pub trait SerializeElements {
+        fn serialize_elements<S: SerializeTuple>(&self, tuple: &mut S) -> Result<(), S::Error>;
+    }
+
+    impl<H, T> SerializeElements for Cons<H, T>
+    where
+        H: Serialize,
+        T: SerializeElements,
+    {
+        fn serialize_elements<S: SerializeTuple>(&self, tuple: &mut S) -> Result<(), S::Error> {
+            tuple.serialize_element(&self.0)?;
+            self.1.serialize_elements(tuple)
+        }
+    }
+
+    impl SerializeElements for Nil {
+        fn serialize_elements<S: SerializeTuple>(&self, _: &mut S) -> Result<(), S::Error> {
+            Ok(())
+        }
+    }
+
+    /// Trait implemented for hlists those elements implement [`Deserialize`], used to
+    /// deserialize [`Cons`] from a flat tuple.
+    pub trait DeserializeElements<'de>: Sized {
+        fn deserialize_elements<A: SeqAccess<'de>>(
+            seq: &mut A,
+            index: usize,
+            expected: &dyn Expected,
+        ) -> Result<Self, A::Error>;
+    }
+
+    impl<'de, H, T> DeserializeElements<'de> for Cons<H, T>
+    where
+        H: Deserialize<'de>,
+        T: DeserializeElements<'de>,
+    {
+        fn deserialize_elements<A: SeqAccess<'de>>(
+            seq: &mut A,
+            index: usize,
+            expected: &dyn Expected,
+        ) -> Result<Self, A::Error> {
+            let head = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(index, expected))?;
+            let tail = T::deserialize_elements(seq, index + 1, expected)?;
+            Ok(Cons(head, tail))
+        }
+    }
+
+    impl<'de> DeserializeElements<'de> for Nil {
+        fn deserialize_elements<A: SeqAccess<'de>>(
+            _: &mut A,
+            _: usize,
+            _: &dyn Expected,
+        ) -> Result<Self, A::Error> {
+            Ok(Nil)
+        }
+    }
+
+    /// Trait implemented for hlists those elements implement [`Serialize`], used to serialize
+    /// [`Nested`](super::Nested).
+    pub trait SerializeNested {
+        fn serialize_nested<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+    }
+
+    impl<H, T> SerializeNested for Cons<H, T>
+    where
+        H: Serialize,
+        T: SerializeNested,
+    {
+        fn serialize_nested<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut pair = serializer.serialize_tuple_struct("Cons", 2)?;
+            pair.serialize_field(&self.0)?;
+            pair.serialize_field(&NestedRef(&self.1))?;
+            pair.end()
+        }
+    }
+
+    impl SerializeNested for Nil {
+        fn serialize_nested<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.serialize(serializer)
+        }
+    }
+
+    struct NestedRef<'a, L>(&'a L);
+
+    impl<L> Serialize for NestedRef<'_, L>
+    where
+        L: SerializeNested,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize_nested(serializer)
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{Cons, Extend, Get, Nil, Reorder, Sculpt};
+
+/// Proof that a hlist contains an element of type `T`.
+///
+/// This is a marker trait that is mostly useful as a bound. `Idx` is a type-level index of the
+/// element (see [`succnum`](crate::succnum)), it's inferred just like with [`Get`] (that's
+/// also why `T` must be unique in the list).
+///
+/// Note that there is no `const CONTAINS: bool`: computing `false` for a type that is *not* in the
+/// list requires type inequality, which is not expressible on stable Rust. Instead, not
+/// containing the type is a compile error.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Contains, Get};
+///
+/// struct Logger(Vec<String>);
+///
+/// fn log<L, Idx>(ctx: &mut L, msg: &str)
+/// where
+///     L: Contains<Logger, Idx> + Get<Idx, Logger>,
+/// {
+///     let logger: &mut Logger = ctx.get_mut();
+///     logger.0.push(msg.to_owned());
+/// }
+///
+/// let mut ctx = hlist![17, Logger(Vec::new())];
+/// log(&mut ctx, "hi");
+/// let logger: &Logger = ctx.get();
+/// assert_eq!(logger.0, ["hi"]);
+/// ```
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, Contains};
+///
+/// struct Logger;
+///
+/// fn needs_logger<L: Contains<Logger, Idx>, Idx>(_: L) {}
+///
+/// needs_logger(hlist![1, 'x']);
+/// ```
+pub trait Contains<T, Idx> {}
+
+impl<L, T, Idx> Contains<T, Idx> for L where L: Get<Idx, T> {}
+
+/// Guard that all elements of a hlist have distinct types.
+///
+/// `Indices` is a HList of type-level indices (see [`succnum`](crate::succnum)). If some type
+/// occurs more than once, its index can't be inferred (just like with [`Get`]) and it's a compile
+/// error.
+///
+/// Note that this is only a guard when `Indices` is inferred: explicitly supplied indices are not
+/// checked, e.g. `HList![U0, U0, U0]` satisfies the bound for `HList![i32, char, i32]`. Don't
+/// rely on this trait for soundness.
+///
+/// Note that there is no `const UNIQUE: bool` for the same reason as with [`Contains`].
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Unique};
+///
+/// fn assert_unique<L: Unique<Indices>, Indices>(_: &L) {}
+///
+/// assert_unique(&hlist![1, 'x', "hi"]);
+/// assert_unique(&hlist![]);
+/// ```
+///
+/// ```compile_fail,E0283
+/// use minihlist::{hlist, Unique};
+///
+/// fn assert_unique<L: Unique<Indices>, Indices>(_: &L) {}
+///
+/// assert_unique(&hlist![1, 'x', 2]);
+/// ```
+pub trait Unique<Indices> {}
+
+impl Unique<Nil> for Nil {}
+
+impl<H, T, Idx, TIdx> Unique<Cons<Idx, TIdx>> for Cons<H, T>
+where
+    Self: Get<Idx, H>,
+    T: Unique<TIdx>,
+{
+}
+
+/// Proof that every element type of a hlist is also an element type of `Other`.
+///
+/// `Indices` is a HList of type-level indices of the elements in `Other` (see
+/// [`succnum`](crate::succnum)), it's inferred just like with [`Get`] (so the types must be
+/// unique in `Other`). Order doesn't matter.
+///
+/// Note that there is no `const SUBSET: bool` for the same reason as with [`Contains`].
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, HList, Subset};
+///
+/// fn assert_subset<A: Subset<B, Indices>, B, Indices>() {}
+///
+/// assert_subset::<HList![char, i32], HList![i32, &str, char], _>();
+/// assert_subset::<HList![], HList![i32], _>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, HList, Subset};
+///
+/// fn assert_subset<A: Subset<B, Indices>, B, Indices>() {}
+///
+/// assert_subset::<HList![char, bool], HList![i32, &str, char], _>();
+/// ```
+pub trait Subset<Other, Indices> {}
+
+impl<Other> Subset<Other, Nil> for Nil {}
+
+impl<H, T, Other, Idx, TIdx> Subset<Other, Cons<Idx, TIdx>> for Cons<H, T>
+where
+    Other: Get<Idx, H>,
+    T: Subset<Other, TIdx>,
+{
+}
+
+// `<L as Sculpt<T, I>>::Remainder`
+type Rem<L, T, I> = <L as Sculpt<T, I>>::Remainder;
+
+/// Union of two hlists viewed as sets of types.
+///
+/// The resulting type `Target` can't be computed on stable Rust (this would require type
+/// inequality), so it has to be specified. The compiler then checks that `Target` consists of
+/// exactly the element types of `Self` and `Other`, each occurring once (in any order).
+///
+/// The elements of `Target` are moved from `self` and `other`. If both lists contain an element of
+/// the same type, the one from `self` is used and the one from `other` is dropped.
+///
+/// `Indices` are type-level indices used by the bounds, they should be inferred (`_`). Both lists
+/// must have unique element types (see [`Unique`], explicitly supplied indices are not checked).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, HList, Union};
+///
+/// let a = hlist![1, 'x'];
+/// let b = hlist!["hi", 'y', 2.5];
+///
+/// let union = a.union::<HList![i32, char, &str, f64], _, _>(b);
+/// assert_eq!(union, hlist![1, 'x', "hi", 2.5]);
+///
+/// // order doesn't matter
+/// let union: HList![char, f64, i32, &str] = a.union(b);
+/// assert_eq!(union, hlist!['x', 2.5, 1, "hi"]);
+/// ```
+///
+/// It's a compile error to specify a type that is not a union:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, HList, Union};
+///
+/// let _ = hlist![1, 'x'].union::<HList![i32, char], _, _>(hlist!["hi"]);
+/// ```
+pub trait Union<Other, Target, Indices> {
+    fn union(self, other: Other) -> Target;
+}
+
+// X  - elements of `Other` that are not in `Self`
+// Rest - elements of `Other` that are also in `Self`
+impl<L, Other, Target, I0, I1, I2, I3, I4> Union<Other, Target, (I0, I1, I2, I3, I4)> for L
+where
+    Target: Sculpt<L, I0> + Unique<I1>,
+    Other: Sculpt<Rem<Target, L, I0>, I2>,
+    Rem<Other, Rem<Target, L, I0>, I2>: Subset<L, I3>,
+    L: Extend<Rem<Target, L, I0>>,
+    L::Output: Reorder<Target, I4>,
+{
+    #[inline]
+    fn union(self, other: Other) -> Target {
+        let (other_only, _) = other.sculpt();
+        self.extend(other_only).reorder()
+    }
+}
+
+/// Intersection of two hlists viewed as sets of types.
+///
+/// The resulting type `Target` has to be specified (see [`Union`] for the reason). The compiler
+/// then checks that `Target` consists of exactly the element types that are in both `Self` and
+/// `Other` (in any order).
+///
+/// The elements of `Target` are moved from `self`, the rest of `self` is dropped.
+///
+/// `Indices` are type-level indices used by the bounds, they should be inferred (`_`). Both lists
+/// must have unique element types (see [`Unique`], explicitly supplied indices are not checked).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, HList, Intersection};
+///
+/// let a = hlist![1, 'x', "hi"];
+/// let b = hlist![2.5, 'y', 2];
+///
+/// let both = a.intersection::<HList![char, i32], _, _>(&b);
+/// assert_eq!(both, hlist!['x', 1]);
+/// ```
+///
+/// It's a compile error to leave out common types:
+///
+/// ```compile_fail,E0283
+/// use minihlist::{hlist, HList, Intersection};
+///
+/// let _ = hlist![1, 'x'].intersection::<HList![i32], _, _>(&hlist!['y', 2]);
+/// ```
+pub trait Intersection<Other, Target, Indices> {
+    fn intersection(self, other: &Other) -> Target;
+}
+
+impl<L, Other, Target, I0, I1, I2> Intersection<Other, Target, (I0, I1, I2)> for L
+where
+    L: Sculpt<Target, I0>,
+    Target: Subset<Other, I1>,
+    // no element left in `Self` is in `Other`
+    Rem<L, Target, I0>: Extend<Other>,
+    <Rem<L, Target, I0> as Extend<Other>>::Output: Unique<I2>,
+{
+    #[inline]
+    fn intersection(self, _: &Other) -> Target {
+        let (target, _) = self.sculpt();
+        target
+    }
+}
+
+/// Difference of two hlists viewed as sets of types.
+///
+/// The resulting type `Target` has to be specified (see [`Union`] for the reason). The compiler
+/// then checks that `Target` consists of exactly the element types of `Self` that are not in
+/// `Other` (in any order).
+///
+/// The elements of `Target` are moved from `self`, the rest of `self` is dropped.
+///
+/// `Indices` are type-level indices used by the bounds, they should be inferred (`_`). Both lists
+/// must have unique element types (see [`Unique`], explicitly supplied indices are not checked).
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, HList, Difference};
+///
+/// let a = hlist![1, 'x', "hi"];
+/// let b = hlist![2.5, 'y'];
+///
+/// let diff = a.difference::<HList![&str, i32], _, _>(&b);
+/// assert_eq!(diff, hlist!["hi", 1]);
+/// ```
+///
+/// It's a compile error to keep types that are in `Other`:
+///
+/// ```compile_fail,E0283
+/// use minihlist::{hlist, HList, Difference};
+///
+/// let _ = hlist![1, 'x'].difference::<HList![i32, char], _, _>(&hlist!['y']);
+/// ```
+pub trait Difference<Other, Target, Indices> {
+    fn difference(self, other: &Other) -> Target;
+}
+
+impl<L, Other, Target, I0, I1, I2> Difference<Other, Target, (I0, I1, I2)> for L
+where
+    L: Sculpt<Target, I0>,
+    Rem<L, Target, I0>: Subset<Other, I1>,
+    // no element of `Target` is in `Other`
+    Target: Extend<Other>,
+    <Target as Extend<Other>>::Output: Unique<I2>,
+{
+    #[inline]
+    fn difference(self, _: &Other) -> Target {
+        let (target, _) = self.sculpt();
+        target
+    }
+}
+
+impl<H, T> Cons<H, T> {
+    /// Moves the elements of `self` and `other` into `Target`. See [`Union`] for more.
+    pub fn union<Target, Other, Indices>(self, other: Other) -> Target
+    where
+        Self: Union<Other, Target, Indices>,
+    {
+        Union::union(self, other)
+    }
+
+    /// Moves the elements of `self` that are also in `other` into `Target`. See [`Intersection`]
+    /// for more.
+    pub fn intersection<Target, Other, Indices>(self, other: &Other) -> Target
+    where
+        Self: Intersection<Other, Target, Indices>,
+    {
+        Intersection::intersection(self, other)
+    }
+
+    /// Moves the elements of `self` that are not in `other` into `Target`. See [`Difference`]
+    /// for more.
+    pub fn difference<Target, Other, Indices>(self, other: &Other) -> Target
+    where
+        Self: Difference<Other, Target, Indices>,
+    {
+        Difference::difference(self, other)
+    }
+}
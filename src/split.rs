@@ -0,0 +1,111 @@
+use crate::{
+    succnum::{Succ, Zero},
+    Cons, Nil,
+};
+
+/// Split hlist at a position.
+///
+/// This is the inverse of [`Extend`](crate::Extend): `list.split_at::<N>()` returns a tuple of
+/// the first `N` elements and the rest of the list. The index is a type-level number from the
+/// [`succnum`](crate::succnum) module, usually passed via the `split_at`, `take` and `skip`
+/// inherent methods of [`Cons`] and [`Nil`].
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, succnum::U2, HList};
+///
+/// let (prefix, suffix): (HList![i32, &str], HList![char]) =
+///     hlist![1, "hi", 'x'].split_at::<U2>();
+/// assert_eq!(prefix, hlist![1, "hi"]);
+/// assert_eq!(suffix, hlist!['x']);
+///
+/// assert_eq!(hlist![1, "hi", 'x'].take::<U2>(), hlist![1, "hi"]);
+/// assert_eq!(hlist![1, "hi", 'x'].skip::<U2>(), hlist!['x']);
+/// ```
+///
+/// It's a compile error to split past the end of the list:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{hlist, succnum::U3};
+///
+/// let _ = hlist![1, 2].split_at::<U3>();
+/// ```
+pub trait SplitAt<Idx> {
+    /// The first `Idx` elements
+    type Prefix;
+
+    /// All elements after the first `Idx`
+    type Suffix;
+
+    /// Splits the list, returning tuple of the prefix and the suffix.
+    fn split_at(self) -> (Self::Prefix, Self::Suffix);
+}
+
+impl SplitAt<Zero> for Nil {
+    type Prefix = Nil;
+    type Suffix = Nil;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        (Nil, Nil)
+    }
+}
+
+impl<H, T> SplitAt<Zero> for Cons<H, T> {
+    type Prefix = Nil;
+    type Suffix = Self;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        (Nil, self)
+    }
+}
+
+impl<H, T, Idx> SplitAt<Succ<Idx>> for Cons<H, T>
+where
+    T: SplitAt<Idx>,
+{
+    type Prefix = Cons<H, T::Prefix>;
+    type Suffix = T::Suffix;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        let Cons(head, tail) = self;
+        let (prefix, suffix) = tail.split_at();
+        (Cons(head, prefix), suffix)
+    }
+}
+
+macro_rules! impl_split_methods {
+    ($( $generics:ident ),* ; $ty:ty) => {
+        impl<$( $generics ),*> $ty {
+            /// Splits the list at `Idx`. See [`SplitAt`] for more.
+            #[allow(clippy::type_complexity)]
+            pub fn split_at<Idx>(
+                self,
+            ) -> (<Self as SplitAt<Idx>>::Prefix, <Self as SplitAt<Idx>>::Suffix)
+            where
+                Self: SplitAt<Idx>,
+            {
+                SplitAt::split_at(self)
+            }
+
+            /// Returns the first `Idx` elements of the list. See [`SplitAt`] for more.
+            pub fn take<Idx>(self) -> <Self as SplitAt<Idx>>::Prefix
+            where
+                Self: SplitAt<Idx>,
+            {
+                SplitAt::split_at(self).0
+            }
+
+            /// Returns the list without the first `Idx` elements. See [`SplitAt`] for more.
+            pub fn skip<Idx>(self) -> <Self as SplitAt<Idx>>::Suffix
+            where
+                Self: SplitAt<Idx>,
+            {
+                SplitAt::split_at(self).1
+            }
+        }
+    };
+}
+
+impl_split_methods!(; Nil);
+impl_split_methods!(H, T; Cons<H, T>);
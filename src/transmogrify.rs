@@ -0,0 +1,113 @@
+use crate::{Cons, Field, LabelledGeneric, Nil, RemoveField};
+
+/// Reshape a record into another record by field labels.
+///
+/// This picks fields of the `Target` record from `Self` by their labels (in any order) and
+/// returns them along with the rest of the fields. Values of the fields with the same label must
+/// have the same type.
+///
+/// `Indices` is a HList of type-level indices of the fields (see [`succnum`](crate::succnum)),
+/// it's inferred just like with [`Get`](crate::Get). Labels must be unique in `Self`.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{labels, record, Record, Transmogrify};
+///
+/// labels!(id, name, age);
+///
+/// let row = record! { id: 1, name: "Ferris", age: 12 };
+///
+/// let (user, rest): (Record! { age: u32, name: &str }, _) = row.transmogrify();
+/// assert_eq!(user, record! { age: 12, name: "Ferris" });
+/// assert_eq!(rest, record! { id: 1 });
+/// ```
+///
+/// It's a compile error to transmogrify into a record with fields missing in the source:
+///
+/// ```compile_fail,E0277
+/// use minihlist::{labels, record, Record, Transmogrify};
+///
+/// labels!(name, age);
+///
+/// let (_, _): (Record! { name: &str, age: u32 }, _) = record! { name: "Ferris" }.transmogrify();
+/// ```
+pub trait Transmogrify<Target, Indices> {
+    /// Fields of `Self` that are not in `Target`
+    type Rest;
+
+    fn transmogrify(self) -> (Target, Self::Rest);
+}
+
+impl<Source> Transmogrify<Nil, Nil> for Source {
+    type Rest = Source;
+
+    #[inline]
+    fn transmogrify(self) -> (Nil, Self::Rest) {
+        (Nil, self)
+    }
+}
+
+// L, V       - label and value of the target head field
+// TT         - target tail
+// Idx, TIdx  - index of the target head field in `Source` and indices of the target tail
+impl<Source, L, V, TT, Idx, TIdx> Transmogrify<Cons<Field<L, V>, TT>, Cons<Idx, TIdx>> for Source
+where
+    Source: RemoveField<L, Idx, Value = V>,
+    Source::Rest: Transmogrify<TT, TIdx>,
+{
+    type Rest = <Source::Rest as Transmogrify<TT, TIdx>>::Rest;
+
+    #[inline]
+    fn transmogrify(self) -> (Cons<Field<L, V>, TT>, Self::Rest) {
+        let (value, rest) = self.remove_field();
+        let (tail, rest) = rest.transmogrify();
+        (Cons(Field::new(value), tail), rest)
+    }
+}
+
+/// Converts a value into another type by field labels of their labelled generic representations.
+///
+/// This converts `src` into its [`LabelledGeneric`] representation, [`Transmogrify`]s it into the
+/// representation of `Dst` and returns `Dst` along with the fields of `src` that are not in `Dst`.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{derive_labelled_generic, labels, record, transmogrify_from};
+///
+/// labels!(id, name, age);
+///
+/// derive_labelled_generic! {
+///     struct UserRow {
+///         id: u64,
+///         name: String,
+///         age: u32,
+///     }
+/// }
+///
+/// derive_labelled_generic! {
+///     #[derive(Debug, PartialEq)]
+///     struct User {
+///         age: u32,
+///         name: String,
+///     }
+/// }
+///
+/// let row = UserRow { id: 1, name: "Ferris".to_owned(), age: 12 };
+/// let (user, rest): (User, _) = transmogrify_from(row);
+///
+/// assert_eq!(user, User { age: 12, name: "Ferris".to_owned() });
+/// assert_eq!(rest, record! { id: 1 });
+/// ```
+pub fn transmogrify_from<Src, Dst, Indices>(
+    src: Src,
+) -> (Dst, <Src::Repr as Transmogrify<Dst::Repr, Indices>>::Rest)
+where
+    Src: LabelledGeneric,
+    Dst: LabelledGeneric,
+    Src::Repr: Transmogrify<Dst::Repr, Indices>,
+{
+    let (repr, rest) = src.into_labelled().transmogrify();
+    (Dst::from_labelled(repr), rest)
+}
@@ -0,0 +1,165 @@
+use crate::{Cons, Nil, Residual, Try};
+
+/// Short-circuiting left fold over a HList.
+///
+/// This is like [`Fold`](crate::Fold), but the function(s) return a [`Try`] type (`Option`,
+/// `Result` or `ControlFlow`) and the fold stops as soon as one of them short-circuits (e.g.
+/// returns `None` or `Err(_)`).
+///
+/// The function(s) type `F` can be:
+/// 1. A `HList` of `FnOnce` functions (one for each element)
+/// 2. A single `FnMut` functions (for homogenous `HList`s)
+/// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
+///    (for homogenous tails)
+///
+/// See examples for more.
+///
+/// The accumulator type can change from function to function, but all functions must
+/// short-circuit with the same residual (`Res`), e.g. they should all return `Result<_, E>` with
+/// the same error type `E`.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use minihlist::{hlist, TryFold};
+///
+/// let parse = |s: &str| s.parse::<u8>().map_err(|_| s.len());
+///
+/// let res = hlist!["1", 2u16, "3"].try_fold(0u8, hlist![
+///     |acc, s| parse(s).map(|x| acc + x),
+///     |acc, i| Ok(u16::from(acc) * i),
+///     |acc, s| parse(s).map(|x| acc + u16::from(x)),
+/// ]);
+/// assert_eq!(res, Ok(5));
+///
+/// let res = hlist!["1", "hello", "x"].try_fold(0u8, hlist![
+///     |acc, s| parse(s).map(|x| acc + x),
+///     |acc, s| parse(s).map(|x| acc + x),
+///     |_, _: &str| -> Result<u8, usize> { unreachable!() },
+/// ]);
+/// assert_eq!(res, Err(5));
+/// ```
+///
+/// Using `FnMut` to fold the homogenous list:
+///
+/// ```
+/// use minihlist::{hlist, TryFold};
+///
+/// let sum = hlist![1u8, 2, 3].try_fold(0u8, u8::checked_add);
+/// assert_eq!(sum, Some(6));
+///
+/// let sum = hlist![1u8, 255, 3].try_fold(0u8, u8::checked_add);
+/// assert_eq!(sum, None);
+/// ```
+///
+/// Using `FnMut` to fold the homogenous tail:
+///
+/// ```
+/// use minihlist::{hlist, TryFold};
+/// use std::ops::ControlFlow;
+///
+/// let res = hlist!["skip", 1, 2, -1, 3].try_fold(0, hlist![
+///     |acc, _: &str| ControlFlow::Continue(acc),
+///     |acc, i| if i < 0 { ControlFlow::Break(acc) } else { ControlFlow::Continue(acc + i) },
+/// ]);
+/// assert_eq!(res, ControlFlow::Break(3));
+/// ```
+pub trait TryFold<Acc, F, Res> {
+    type Output: Try<Residual = Res>;
+
+    fn try_fold(self, acc: Acc, f: F) -> Self::Output;
+}
+
+// Acc      - accumulator,
+// Res      - residual of the functions
+// F{H,M,T} - function {head,middle,tail} parts
+// {H,M,T}  - {head,middle,tail} of the hlist being folded
+// R        - result of `FH`
+impl<Acc, Res, FH, FM, FT, H, M, T, R> TryFold<Acc, Cons<FH, Cons<FM, FT>>, Res>
+    for Cons<H, Cons<M, T>>
+where
+    FH: FnOnce(Acc, H) -> R,
+    R: Try<Residual = Res>,
+    Cons<M, T>: TryFold<R::Output, Cons<FM, FT>, Res>,
+{
+    type Output = <Cons<M, T> as TryFold<R::Output, Cons<FM, FT>, Res>>::Output;
+
+    #[inline]
+    fn try_fold(self, acc: Acc, Cons(fh, ft): Cons<FH, Cons<FM, FT>>) -> Self::Output {
+        let Cons(head, tail) = self;
+        tail.try_fold(tri!(fh(acc, head)), ft)
+    }
+}
+
+impl<Acc, Res, H, F, R> TryFold<Acc, Cons<F, Nil>, Res> for Cons<H, Nil>
+where
+    F: FnOnce(Acc, H) -> R,
+    R: Try<Residual = Res>,
+{
+    type Output = R;
+
+    #[inline]
+    fn try_fold(self, acc: Acc, Cons(f, _): Cons<F, Nil>) -> Self::Output {
+        let Cons(head, _) = self;
+        f(acc, head)
+    }
+}
+
+impl<Acc, Res, H, T, F, R> TryFold<Acc, F, Res> for Cons<H, T>
+where
+    F: FnMut(Acc, H) -> R,
+    R: Try<Output = Acc, Residual = Res>,
+    T: TryFold<Acc, F, Res>,
+{
+    type Output = T::Output;
+
+    #[inline]
+    fn try_fold(self, acc: Acc, mut f: F) -> Self::Output {
+        let Cons(head, tail) = self;
+        tail.try_fold(tri!(f(acc, head)), f)
+    }
+}
+
+impl<Acc, Res, H, T0, T1, F> TryFold<Acc, Cons<F, Nil>, Res> for Cons<H, Cons<T0, T1>>
+where
+    Self: TryFold<Acc, F, Res>,
+{
+    type Output = <Self as TryFold<Acc, F, Res>>::Output;
+
+    #[inline]
+    fn try_fold(self, acc: Acc, Cons(f, _): Cons<F, Nil>) -> Self::Output {
+        self.try_fold(acc, f)
+    }
+}
+
+impl<Acc, Res, F> TryFold<Acc, F, Res> for Nil
+where
+    Res: Residual<Acc>,
+{
+    type Output = Res::TryType;
+
+    #[inline]
+    fn try_fold(self, acc: Acc, _: F) -> Self::Output {
+        Try::from_output(acc)
+    }
+}
+
+#[test]
+fn short_circuits() {
+    use crate::hlist;
+
+    let mut visited = Vec::new();
+    let res = hlist![1, 2, 3, 4].try_fold(0, |acc, i| {
+        visited.push(i);
+        if i == 2 {
+            Err(acc)
+        } else {
+            Ok(acc + i)
+        }
+    });
+
+    assert_eq!(res, Err(1));
+    assert_eq!(visited, [1, 2]);
+}
@@ -0,0 +1,141 @@
+use crate::{Cons, Nil, Residual, Try};
+
+/// Short-circuiting map over a HList.
+///
+/// This is like [`Map`](crate::Map), but the function(s) return a [`Try`] type (`Option`,
+/// `Result` or `ControlFlow`) and mapping stops as soon as one of them short-circuits (e.g.
+/// returns `None` or `Err(_)`).
+///
+/// The function(s) type `F` can be:
+/// 1. A `HList` of `FnOnce` functions (one for each element)
+/// 2. A single `FnMut` function (for homogeneous `HList`s) (Note: just like with
+///    [`Map`](crate::Map), you need a mutable reference to `FnMut` - `&'_ mut impl FnMut`)
+/// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
+///    (for homogeneous tails)
+///
+/// See examples for more.
+///
+/// All functions must short-circuit with the same residual (`Res`), e.g. they should all return
+/// `Result<_, E>` with the same error type `E`.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use minihlist::{hlist, TryMap};
+///
+/// let f = || hlist![|s: &str| s.parse::<i32>().ok(), |s: &str| s.chars().next()];
+///
+/// assert_eq!(hlist!["1", "x"].try_map(f()), Some(hlist![1, 'x']));
+/// assert_eq!(hlist!["one", "x"].try_map(f()), None);
+/// ```
+///
+/// Using `FnMut` to map the homogenous list:
+///
+/// ```
+/// use minihlist::{hlist, TryMap};
+///
+/// let res = hlist!["1", "2", "3"].try_map(&mut str::parse::<u8>);
+/// assert_eq!(res, Ok(hlist![1, 2, 3]));
+///
+/// let res = hlist!["1", "1000", "3"].try_map(&mut str::parse::<u8>);
+/// assert!(res.is_err());
+/// ```
+///
+/// Using `FnMut` to map the homogenous tail:
+///
+/// ```
+/// use minihlist::{hlist, TryMap};
+///
+/// let res = hlist![1.5, 12, 16].try_map(hlist![
+///     |f: f64| Some(f as i32),
+///     &mut |i: i32| i.checked_mul(2), // <-- `FnMut` here
+/// ]);
+/// assert_eq!(res, Some(hlist![1, 24, 32]));
+/// ```
+pub trait TryMap<F, Res> {
+    type Output: Try<Residual = Res>;
+
+    fn try_map(self, f: F) -> Self::Output;
+}
+
+// Res      - residual of the functions
+// F{H,M,T} - function {head,middle,tail} parts
+// {H,M,T}  - {head,middle,tail} of the hlist being mapped
+// R        - result of `FH`
+impl<Res, FH, FM, FT, H, M, T, R> TryMap<Cons<FH, Cons<FM, FT>>, Res> for Cons<H, Cons<M, T>>
+where
+    FH: FnOnce(H) -> R,
+    R: Try<Residual = Res>,
+    Cons<M, T>: TryMap<Cons<FM, FT>, Res>,
+    Res: Residual<
+        Cons<R::Output, <<Cons<M, T> as TryMap<Cons<FM, FT>, Res>>::Output as Try>::Output>,
+    >,
+{
+    #[allow(clippy::type_complexity)]
+    type Output = <Res as Residual<
+        Cons<R::Output, <<Cons<M, T> as TryMap<Cons<FM, FT>, Res>>::Output as Try>::Output>,
+    >>::TryType;
+
+    fn try_map(self, Cons(fh, ft): Cons<FH, Cons<FM, FT>>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = tri!(fh(head));
+        let tail = tri!(tail.try_map(ft));
+        Try::from_output(Cons(head, tail))
+    }
+}
+
+impl<Res, F, H, R> TryMap<Cons<F, Nil>, Res> for Cons<H, Nil>
+where
+    F: FnOnce(H) -> R,
+    R: Try<Residual = Res>,
+    Res: Residual<Cons<R::Output, Nil>>,
+{
+    type Output = Res::TryType;
+
+    fn try_map(self, Cons(f, _): Cons<F, Nil>) -> Self::Output {
+        let Cons(head, _) = self;
+        Try::from_output(Cons(tri!(f(head)), Nil))
+    }
+}
+
+// See the comment on the analogous `Map` impl
+impl<'a, Res, F, H, T, R> TryMap<&'a mut F, Res> for Cons<H, T>
+where
+    F: FnMut(H) -> R,
+    R: Try<Residual = Res>,
+    T: TryMap<&'a mut F, Res>,
+    Res: Residual<Cons<R::Output, <T::Output as Try>::Output>>,
+{
+    type Output = Res::TryType;
+
+    fn try_map(self, f: &'a mut F) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = tri!(f(head));
+        let tail = tri!(tail.try_map(f));
+        Try::from_output(Cons(head, tail))
+    }
+}
+
+impl<Res, F, H, M, T> TryMap<Cons<F, Nil>, Res> for Cons<H, Cons<M, T>>
+where
+    Self: TryMap<F, Res>,
+{
+    type Output = <Self as TryMap<F, Res>>::Output;
+
+    fn try_map(self, Cons(f, _): Cons<F, Nil>) -> Self::Output {
+        self.try_map(f)
+    }
+}
+
+impl<Res, F> TryMap<F, Res> for Nil
+where
+    Res: Residual<Nil>,
+{
+    type Output = Res::TryType;
+
+    fn try_map(self, _: F) -> Self::Output {
+        Try::from_output(Nil)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{Cons, Nil, Residual, Try};
+
+/// Short-circuiting right fold over a HList.
+///
+/// This is like [`FoldRight`](crate::FoldRight), but the function(s) return a [`Try`] type
+/// (`Option`, `Result` or `ControlFlow`) and the fold stops as soon as one of them
+/// short-circuits (e.g. returns `None` or `Err(_)`).
+///
+/// The function(s) type `F` can be:
+/// 1. A `HList` of `FnOnce` functions (one for each element)
+/// 2. A single `FnMut` functions (for homogenous `HList`s)
+/// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
+///    (for homogenous tails)
+///
+/// See examples for more.
+///
+/// The accumulator type can change from function to function, but all functions must
+/// short-circuit with the same residual (`Res`), e.g. they should all return `Result<_, E>` with
+/// the same error type `E`.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use minihlist::{hlist, TryFoldRight};
+///
+/// let res = hlist!["a", 3u8].try_rfold(String::new(), hlist![
+///     |acc: String, s: &str| Some(acc + s),
+///     |acc: String, i: u8| Some(acc + &i.to_string()),
+/// ]);
+/// assert_eq!(res, Some(String::from("3a")));
+/// ```
+///
+/// Using `FnMut` to fold the homogenous list:
+///
+/// ```
+/// use minihlist::{hlist, TryFoldRight};
+///
+/// let mut visited = Vec::new();
+/// let res = hlist![1u8, 255, 3].try_rfold(0u8, |acc: u8, i| {
+///     visited.push(i);
+///     acc.checked_add(i)
+/// });
+/// assert_eq!(res, None);
+/// assert_eq!(visited, [3, 255]);
+/// ```
+///
+/// Using `FnMut` to fold the homogenous tail:
+///
+/// ```
+/// use minihlist::{hlist, TryFoldRight};
+///
+/// let res = hlist!["x", 1, 2].try_rfold(0, hlist![
+///     |acc, s: &str| s.parse::<i32>().map(|x| acc + x),
+///     |acc, i| Ok(acc + i), // <-- `FnMut` here
+/// ]);
+/// assert!(res.is_err());
+/// ```
+pub trait TryFoldRight<Acc, F, Res> {
+    type Output: Try<Residual = Res>;
+
+    fn try_rfold(self, acc: Acc, f: F) -> Self::Output;
+}
+
+// Acc      - accumulator,
+// Res      - residual of the functions
+// F{H,M,T} - function {head,middle,tail} parts
+// {H,M,T}  - {head,middle,tail} of the hlist being folded
+// R        - result of `FH`
+impl<Acc, Res, H, M, T, FH, FM, FT, R> TryFoldRight<Acc, Cons<FH, Cons<FM, FT>>, Res>
+    for Cons<H, Cons<M, T>>
+where
+    Cons<M, T>: TryFoldRight<Acc, Cons<FM, FT>, Res>,
+    FH: FnOnce(
+        <<Cons<M, T> as TryFoldRight<Acc, Cons<FM, FT>, Res>>::Output as Try>::Output,
+        H,
+    ) -> R,
+    R: Try<Residual = Res>,
+{
+    type Output = R;
+
+    #[inline]
+    fn try_rfold(self, acc: Acc, Cons(fh, ft): Cons<FH, Cons<FM, FT>>) -> Self::Output {
+        let Cons(head, tail) = self;
+        fh(tri!(tail.try_rfold(acc, ft)), head)
+    }
+}
+
+impl<Acc, Res, H, F, R> TryFoldRight<Acc, Cons<F, Nil>, Res> for Cons<H, Nil>
+where
+    F: FnOnce(Acc, H) -> R,
+    R: Try<Residual = Res>,
+{
+    type Output = R;
+
+    #[inline]
+    fn try_rfold(self, acc: Acc, Cons(f, _): Cons<F, Nil>) -> Self::Output {
+        let Cons(head, _) = self;
+        f(acc, head)
+    }
+}
+
+impl<Acc, Res, H, T, F, R> TryFoldRight<Acc, F, Res> for Cons<H, T>
+where
+    for<'a> T: TryFoldRight<Acc, &'a mut F, Res, Output = R>,
+    F: FnMut(Acc, H) -> R,
+    R: Try<Output = Acc, Residual = Res>,
+{
+    type Output = R;
+
+    #[inline]
+    fn try_rfold(self, acc: Acc, mut f: F) -> Self::Output {
+        let Cons(head, tail) = self;
+        let acc = tri!(tail.try_rfold(acc, &mut f));
+        f(acc, head)
+    }
+}
+
+impl<Acc, Res, H, T0, T1, F> TryFoldRight<Acc, Cons<F, Nil>, Res> for Cons<H, Cons<T0, T1>>
+where
+    Self: TryFoldRight<Acc, F, Res>,
+{
+    type Output = <Self as TryFoldRight<Acc, F, Res>>::Output;
+
+    #[inline]
+    fn try_rfold(self, acc: Acc, Cons(f, _): Cons<F, Nil>) -> Self::Output {
+        self.try_rfold(acc, f)
+    }
+}
+
+impl<Acc, Res, F> TryFoldRight<Acc, F, Res> for Nil
+where
+    Res: Residual<Acc>,
+{
+    type Output = Res::TryType;
+
+    #[inline]
+    fn try_rfold(self, acc: Acc, _: F) -> Self::Output {
+        Try::from_output(acc)
+    }
+}
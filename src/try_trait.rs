@@ -0,0 +1,118 @@
+use core::{convert::Infallible, ops::ControlFlow};
+
+/// Stable analog of the unstable `std::ops::Try` trait.
+///
+/// It's implemented for [`Option`], [`Result`] and [`ControlFlow`] and is used by short-circuiting
+/// combinators like [`TryFold`](crate::TryFold) and [`TryMap`](crate::TryMap).
+pub trait Try {
+    /// The type of the value produced by `?` when *not* short-circuiting
+    type Output;
+
+    /// The type of the value passed to [`from_residual`](Try::from_residual) when
+    /// short-circuiting (e.g. `Option<Infallible>` for `Option<T>`)
+    type Residual;
+
+    /// Constructs the type from its `Output` type.
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Constructs the type from a compatible `Residual` type.
+    fn from_residual(residual: Self::Residual) -> Self;
+
+    /// Decides whether the operator should produce a value or propagate a value back to the
+    /// caller.
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+/// Allows retrieving the canonical [`Try`] type implementing it for a residual and an output type.
+///
+/// E.g. `<Option<Infallible> as Residual<T>>::TryType` is `Option<T>`.
+pub trait Residual<O> {
+    type TryType: Try<Output = O, Residual = Self>;
+}
+
+impl<T> Try for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        Some(output)
+    }
+
+    #[inline]
+    fn from_residual(_: Self::Residual) -> Self {
+        None
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Some(output) => ControlFlow::Continue(output),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+impl<O> Residual<O> for Option<Infallible> {
+    type TryType = Option<O>;
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        Ok(output)
+    }
+
+    #[inline]
+    fn from_residual(residual: Self::Residual) -> Self {
+        match residual {
+            Err(err) => Err(err),
+            Ok(never) => match never {},
+        }
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Ok(output) => ControlFlow::Continue(output),
+            Err(err) => ControlFlow::Break(Err(err)),
+        }
+    }
+}
+
+impl<O, E> Residual<O> for Result<Infallible, E> {
+    type TryType = Result<O, E>;
+}
+
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, Infallible>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    #[inline]
+    fn from_residual(residual: Self::Residual) -> Self {
+        match residual {
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+            ControlFlow::Continue(never) => match never {},
+        }
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            ControlFlow::Continue(output) => ControlFlow::Continue(output),
+            ControlFlow::Break(b) => ControlFlow::Break(ControlFlow::Break(b)),
+        }
+    }
+}
+
+impl<O, B> Residual<O> for ControlFlow<B, Infallible> {
+    type TryType = ControlFlow<B, O>;
+}
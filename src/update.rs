@@ -0,0 +1,104 @@
+use crate::{
+    succnum::{Succ, Zero},
+    Cons, Get,
+};
+use core::mem;
+
+/// Type-directed replacement of a hlist element.
+///
+/// This trait allows to replace an element of a hlist just by type, returning the old value. Just
+/// like with [`Get`], the type must be unique in the list.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Replace};
+///
+/// let mut list = hlist![1, 'x', "hi"];
+///
+/// assert_eq!(list.replace('y'), 'x');
+/// assert_eq!(list.replace::<i32, _>(2), 1);
+/// assert_eq!(list, hlist![2, 'y', "hi"]);
+/// ```
+pub trait Replace<T, Idx> {
+    fn replace(&mut self, value: T) -> T;
+}
+
+impl<L, T, Idx> Replace<T, Idx> for L
+where
+    L: Get<Idx, T>,
+{
+    #[inline]
+    fn replace(&mut self, value: T) -> T {
+        mem::replace(self.get_mut(), value)
+    }
+}
+
+/// Type-directed update of a hlist element.
+///
+/// This trait allows to apply a function to an element of a hlist just by type. Unlike with
+/// [`Get::get_mut`], the function may change the type of the element, the resulting list type
+/// reflects the change. Just like with [`Get`], the type must be unique in the list.
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Update};
+///
+/// let list = hlist![1, 'x', "hi"];
+///
+/// let list = list.update(|s: &str| s.len());
+/// assert_eq!(list, hlist![1, 'x', 2]);
+///
+/// let list = list.update::<char, _, _>(|c| c.to_ascii_uppercase());
+/// assert_eq!(list, hlist![1, 'X', 2]);
+/// ```
+pub trait Update<T, Idx, F> {
+    type Output;
+
+    fn update(self, f: F) -> Self::Output;
+}
+
+impl<T, Tail, F, U> Update<T, Zero, F> for Cons<T, Tail>
+where
+    F: FnOnce(T) -> U,
+{
+    type Output = Cons<U, Tail>;
+
+    #[inline]
+    fn update(self, f: F) -> Self::Output {
+        let Cons(head, tail) = self;
+        Cons(f(head), tail)
+    }
+}
+
+impl<H, Tail, T, Idx, F> Update<T, Succ<Idx>, F> for Cons<H, Tail>
+where
+    Tail: Update<T, Idx, F>,
+{
+    type Output = Cons<H, Tail::Output>;
+
+    #[inline]
+    fn update(self, f: F) -> Self::Output {
+        let Cons(head, tail) = self;
+        Cons(head, tail.update(f))
+    }
+}
+
+impl<H, T> Cons<H, T> {
+    /// Replaces the element of type `X`, returning the old value. See [`Replace`] for more.
+    pub fn replace<X, Idx>(&mut self, value: X) -> X
+    where
+        Self: Replace<X, Idx>,
+    {
+        Replace::replace(self, value)
+    }
+
+    /// Applies `f` to the element of type `X`. See [`Update`] for more.
+    pub fn update<X, Idx, F>(self, f: F) -> <Self as Update<X, Idx, F>>::Output
+    where
+        Self: Update<X, Idx, F>,
+    {
+        Update::update(self, f)
+    }
+}
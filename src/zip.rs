@@ -0,0 +1,230 @@
+use crate::{pure, Cons, Nil};
+
+/// Zip two hlists of the same length into a single hlist of pairs.
+///
+/// Note: `hlist![a0, a1, ..., an].zip(hlist![b0, b1, ..., bn])` is
+/// `hlist![(a0, b0), (a1, b1), ..., (an, bn)]`
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Zip};
+///
+/// let res = hlist![1, "hi"].zip(hlist!['x', false]);
+/// assert_eq!(res, hlist![(1, 'x'), ("hi", false)]);
+/// ```
+///
+/// It's a compile error to zip lists of different lengths:
+///
+/// ```compile_fail,E0308
+/// use minihlist::{hlist, Zip};
+///
+/// let _ = hlist![1, 2].zip(hlist![3]);
+/// ```
+pub trait Zip<Other> {
+    type Output;
+
+    fn zip(self, other: Other) -> Self::Output;
+}
+
+impl<H, T, OH, OT> Zip<Cons<OH, OT>> for Cons<H, T>
+where
+    T: Zip<OT>,
+{
+    type Output = Cons<(H, OH), T::Output>;
+
+    fn zip(self, Cons(other_head, other_tail): Cons<OH, OT>) -> Self::Output {
+        let Cons(head, tail) = self;
+        Cons((head, other_head), tail.zip(other_tail))
+    }
+}
+
+impl Zip<Nil> for Nil {
+    type Output = Nil;
+
+    fn zip(self, _: Nil) -> Self::Output {
+        Nil
+    }
+}
+
+/// Unzip hlist of pairs into two hlists.
+///
+/// This is the inverse of [`Zip`].
+///
+/// ## Examples
+///
+/// ```
+/// use minihlist::{hlist, Unzip};
+///
+/// let (left, right) = hlist![(1, 'x'), ("hi", false)].unzip();
+/// assert_eq!(left, hlist![1, "hi"]);
+/// assert_eq!(right, hlist!['x', false]);
+/// ```
+pub trait Unzip {
+    type Left;
+    type Right;
+
+    fn unzip(self) -> (Self::Left, Self::Right);
+}
+
+impl<A, B, T> Unzip for Cons<(A, B), T>
+where
+    T: Unzip,
+{
+    type Left = Cons<A, T::Left>;
+    type Right = Cons<B, T::Right>;
+
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        let Cons((a, b), tail) = self;
+        let (left, right) = tail.unzip();
+        (Cons(a, left), Cons(b, right))
+    }
+}
+
+impl Unzip for Nil {
+    type Left = Nil;
+    type Right = Nil;
+
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        (Nil, Nil)
+    }
+}
+
+/// Zip two hlists of the same length applying a function to each pair of elements.
+///
+/// The function(s) type `F` can be:
+/// 1. A `HList` of `FnOnce` functions (one for each pair)
+/// 2. A single `FnMut` function (for homogeneous `HList`s) (Note: just like with [`Map`], you
+///    need a mutable reference to `FnMut` - `&'_ mut impl FnMut`)
+/// 3. Combination of 1 and 2: a `HList` of `FnOnce` functions last of which is `FnMut` function
+///    (for homogeneous tails)
+///
+/// See examples for more.
+///
+/// Note: `hlist![a0, ..., an].zip_with(hlist![b0, ..., bn], hlist![f0, ..., fn])` is essentially
+/// `hlist![f0(a0, b0), ..., fn(an, bn)]`
+///
+/// [`Map`]: crate::Map
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use minihlist::{hlist, ZipWith};
+/// let res = hlist![1, "hi"].zip_with(
+///     hlist![2, 'x'],
+///     hlist![|a, b| a + b, |s: &str, c: char| format!("{}{}", s, c)],
+/// );
+/// assert_eq!(res, hlist![3, "hix"]);
+/// ```
+///
+/// Using `FnMut` to zip homogenous lists:
+///
+/// ```
+/// use minihlist::{hlist, ZipWith};
+/// use std::ops::Mul;
+///
+/// let res = hlist![1, 2, 3].zip_with(hlist![4, 5, 6], &mut Mul::mul);
+/// assert_eq!(res, hlist![4, 10, 18]);
+/// ```
+///
+/// Using `FnMut` to zip homogenous tails:
+///
+/// ```
+/// use minihlist::{hlist, ZipWith};
+/// let res = hlist!["a", 1, 2].zip_with(
+///     hlist!["b", 3, 4],
+///     hlist![
+///         |a: &str, b: &str| a.len() + b.len(),
+///         &mut |a: i32, b: i32| (a + b) as usize, // <-- `FnMut` here
+///     ],
+/// );
+/// assert_eq!(res, hlist![2, 4, 6]);
+/// ```
+pub trait ZipWith<Other, F> {
+    type Output;
+
+    fn zip_with(self, other: Other, f: F) -> Self::Output;
+}
+
+// F{H,M,T} - function {head,middle,tail} parts
+// {H,M,T}  - {head,middle,tail} of the hlist being zipped
+// O{H,T}   - {head,tail} of the other hlist
+// R        - result of `FH`
+impl<FH, FM, FT, H, M, T, OH, OT, R> ZipWith<Cons<OH, OT>, Cons<FH, Cons<FM, FT>>>
+    for Cons<H, Cons<M, T>>
+where
+    FH: FnOnce(H, OH) -> R,
+    Cons<M, T>: ZipWith<OT, Cons<FM, FT>>,
+{
+    #[allow(clippy::type_complexity)]
+    type Output = Cons<R, <Cons<M, T> as ZipWith<OT, Cons<FM, FT>>>::Output>;
+
+    fn zip_with(
+        self,
+        Cons(other_head, other_tail): Cons<OH, OT>,
+        Cons(fh, ft): Cons<FH, Cons<FM, FT>>,
+    ) -> Self::Output {
+        let Cons(head, tail) = self;
+        Cons(fh(head, other_head), tail.zip_with(other_tail, ft))
+    }
+}
+
+impl<F, H, OH, R> ZipWith<Cons<OH, Nil>, Cons<F, Nil>> for Cons<H, Nil>
+where
+    F: FnOnce(H, OH) -> R,
+{
+    type Output = Cons<R, Nil>;
+
+    fn zip_with(
+        self,
+        Cons(other_head, _): Cons<OH, Nil>,
+        Cons(f, _): Cons<F, Nil>,
+    ) -> Self::Output {
+        let Cons(head, _) = self;
+        pure(f(head, other_head))
+    }
+}
+
+// See the comment on the analogous `Map` impl
+impl<'a, F, H, T, OH, OT, R> ZipWith<Cons<OH, OT>, &'a mut F> for Cons<H, T>
+where
+    F: FnMut(H, OH) -> R,
+    T: ZipWith<OT, &'a mut F>,
+{
+    type Output = Cons<R, T::Output>;
+
+    fn zip_with(self, Cons(other_head, other_tail): Cons<OH, OT>, f: &'a mut F) -> Self::Output {
+        let Cons(head, tail) = self;
+        Cons(f(head, other_head), tail.zip_with(other_tail, f))
+    }
+}
+
+impl<F, H, M, T, Other> ZipWith<Other, Cons<F, Nil>> for Cons<H, Cons<M, T>>
+where
+    Self: ZipWith<Other, F>,
+{
+    type Output = <Self as ZipWith<Other, F>>::Output;
+
+    fn zip_with(self, other: Other, Cons(f, _): Cons<F, Nil>) -> Self::Output {
+        self.zip_with(other, f)
+    }
+}
+
+impl<F> ZipWith<Nil, F> for Nil {
+    type Output = Nil;
+
+    fn zip_with(self, _: Nil, _: F) -> Self::Output {
+        Nil
+    }
+}
+
+#[test]
+fn zip_unzip() {
+    use crate::hlist;
+
+    let list = hlist![1, 'x', "hi"].zip(hlist![false, 2u8, ()]);
+    assert_eq!(list, hlist![(1, false), ('x', 2u8), ("hi", ())]);
+    assert_eq!(list.unzip(), (hlist![1, 'x', "hi"], hlist![false, 2u8, ()]));
+}